@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use criterion::{BatchSize, Criterion, Throughput, criterion_group, criterion_main};
+use spellabet::{PhoneticConverter, SpellingAlphabet};
+
+const SHORT_WORDS: [&str; 4] = ["Hi", "Example123!", "N0t4Pa$$", "Zürich"];
+
+const LINE: &str = "The quick brown fox jumps over the lazy dog, then pays $42.50 \
+                    for 3 coffees at 07:15 (order #A9-771). ";
+
+// Repeat the line until the text is at least the given number of bytes.
+fn text_of_len(len: usize) -> String {
+    LINE.repeat(len.div_ceil(LINE.len()))
+}
+
+fn initialize(c: &mut Criterion) {
+    c.bench_function("initialize", |b| {
+        b.iter(|| PhoneticConverter::new(black_box(&SpellingAlphabet::Nato)));
+    });
+}
+
+fn shared_with_overrides(c: &mut Criterion) {
+    c.bench_function("shared_with_overrides", |b| {
+        b.iter(|| {
+            let overrides_map = HashMap::from([('h', "Hawaii".to_string())]);
+            PhoneticConverter::shared(black_box(SpellingAlphabet::Nato))
+                .clone()
+                .with_overrides(overrides_map)
+        });
+    });
+}
+
+fn short_words(c: &mut Criterion) {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato);
+    c.bench_function("short_words", |b| {
+        b.iter(|| {
+            for word in SHORT_WORDS {
+                black_box(converter.convert(black_box(word)));
+            }
+        });
+    });
+}
+
+fn long_line(c: &mut Criterion) {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato);
+    let text = text_of_len(1_000);
+    let mut group = c.benchmark_group("long_line");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("default", |b| {
+        b.iter(|| converter.convert(black_box(&text)));
+    });
+    let nonce_converter = converter.clone().nonce_form(true);
+    group.bench_function("nonce_form", |b| {
+        b.iter(|| nonce_converter.convert(black_box(&text)));
+    });
+    group.finish();
+}
+
+fn large_file(c: &mut Criterion) {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato);
+    let text = text_of_len(1 << 20);
+    let mut group = c.benchmark_group("large_file");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("convert", |b| {
+        b.iter(|| converter.convert(black_box(&text)));
+    });
+    group.bench_function("write_converted", |b| {
+        b.iter_batched_ref(
+            || String::with_capacity(text.len() * 8),
+            |output| converter.write_converted(black_box(&text), output),
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    initialize,
+    shared_with_overrides,
+    short_words,
+    long_line,
+    large_file
+);
+criterion_main!(benches);
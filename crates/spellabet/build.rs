@@ -0,0 +1,164 @@
+// Generates the static code word tables from `data/code_words.rs`.
+//
+// Each table is sorted by character so that code words can be found with a
+// binary search, and the lowercase and uppercase forms of each code word are
+// computed here rather than when the tables are used, along with the words
+// within it, as marked in the data. Each table also has an
+// index of its characters, sorted by code word, for reverse lookups.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "data/code_words.rs"]
+mod code_words;
+
+use code_words::{
+    ARABIC_ALPHABET, ARABIC_DIGITS_AND_SYMBOLS, BULGARIAN_ALPHABET, BULGARIAN_DIGITS_AND_SYMBOLS,
+    DEFAULT_DIGITS_AND_SYMBOLS, DUTCH_DIGITS_AND_SYMBOLS, FORMAL_DIGITS_AND_SYMBOLS,
+    FRENCH_DIGITS_AND_SYMBOLS, GERMAN_DIGITS_AND_SYMBOLS, GREEK_ALPHABET, GREEK_DIGITS_AND_SYMBOLS,
+    HEBREW_ALPHABET, HEBREW_DIGITS_AND_SYMBOLS, ICAO_1951_DRAFT_ALPHABET,
+    ITALIAN_DIGITS_AND_SYMBOLS, ITU_1927_ALPHABET, ITU_1938_ALPHABET, JAN_ALPHABET,
+    JAPANESE_DIGITS_AND_SYMBOLS, KOREAN_ALPHABET, KOREAN_DIGITS_AND_SYMBOLS, LAPD_ALPHABET,
+    MILITARY_DIGITS_AND_SYMBOLS, NATO_ALPHABET, PROGRAMMER_DIGITS_AND_SYMBOLS, PRONUNCIATIONS,
+    RAF_1924_ALPHABET, RAF_1942_ALPHABET, ROYAL_NAVY_ALPHABET, RUSSIAN_ALPHABET,
+    RUSSIAN_DIGITS_AND_SYMBOLS, SPANISH_DIGITS_AND_SYMBOLS, UKRAINIAN_ALPHABET,
+    UKRAINIAN_DIGITS_AND_SYMBOLS, US_FINANCIAL_ALPHABET, US_TELEPHONE_ALPHABET, WABUN_ALPHABET,
+    WESTERN_UNION_ALPHABET,
+};
+
+// The mark between words that are written joined, like "Double|Quote".
+const WORD_JOINER: char = '|';
+
+macro_rules! tables {
+    ($($name:ident),+ $(,)?) => {
+        [$((stringify!($name), &$name[..])),+]
+    };
+}
+
+fn main() {
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=data/code_words.rs");
+
+    let tables = tables!(
+        ARABIC_ALPHABET,
+        ARABIC_DIGITS_AND_SYMBOLS,
+        BULGARIAN_ALPHABET,
+        BULGARIAN_DIGITS_AND_SYMBOLS,
+        DEFAULT_DIGITS_AND_SYMBOLS,
+        DUTCH_DIGITS_AND_SYMBOLS,
+        FORMAL_DIGITS_AND_SYMBOLS,
+        FRENCH_DIGITS_AND_SYMBOLS,
+        GERMAN_DIGITS_AND_SYMBOLS,
+        GREEK_ALPHABET,
+        GREEK_DIGITS_AND_SYMBOLS,
+        HEBREW_ALPHABET,
+        HEBREW_DIGITS_AND_SYMBOLS,
+        ICAO_1951_DRAFT_ALPHABET,
+        ITALIAN_DIGITS_AND_SYMBOLS,
+        ITU_1927_ALPHABET,
+        ITU_1938_ALPHABET,
+        JAN_ALPHABET,
+        JAPANESE_DIGITS_AND_SYMBOLS,
+        KOREAN_ALPHABET,
+        KOREAN_DIGITS_AND_SYMBOLS,
+        LAPD_ALPHABET,
+        MILITARY_DIGITS_AND_SYMBOLS,
+        NATO_ALPHABET,
+        PROGRAMMER_DIGITS_AND_SYMBOLS,
+        RAF_1924_ALPHABET,
+        RAF_1942_ALPHABET,
+        ROYAL_NAVY_ALPHABET,
+        RUSSIAN_ALPHABET,
+        RUSSIAN_DIGITS_AND_SYMBOLS,
+        SPANISH_DIGITS_AND_SYMBOLS,
+        UKRAINIAN_ALPHABET,
+        UKRAINIAN_DIGITS_AND_SYMBOLS,
+        US_FINANCIAL_ALPHABET,
+        US_TELEPHONE_ALPHABET,
+        WABUN_ALPHABET,
+        WESTERN_UNION_ALPHABET,
+    );
+
+    let mut output = String::new();
+    for (name, table) in tables {
+        write_table(&mut output, name, table);
+    }
+    write_pronunciations(&mut output);
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("code_words.rs");
+    fs::write(path, output).unwrap();
+}
+
+fn write_table(output: &mut String, name: &str, table: &[(char, &str)]) {
+    let mut code_words: Vec<(char, String, Vec<&str>)> = table
+        .iter()
+        .map(|(character, code_word)| {
+            let text = code_word.replace(WORD_JOINER, "");
+            let words = code_word
+                .split(|c: char| c.is_whitespace() || c == WORD_JOINER)
+                .filter(|word| !word.is_empty())
+                .collect();
+            (*character, text, words)
+        })
+        .collect();
+    code_words.sort_by_key(|(character, _, _)| *character);
+    if let Some(pair) = code_words.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+        panic!("{name} has more than one code word for {:?}", pair[0].0);
+    }
+
+    let mut index: Vec<(String, char)> = code_words
+        .iter()
+        .map(|(character, code_word, _)| (index_key(code_word), *character))
+        .collect();
+    index.sort();
+
+    writeln!(output, "pub static {name}: Table = Table {{").unwrap();
+    writeln!(output, "    code_words: &[").unwrap();
+    for (character, code_word, words) in &code_words {
+        let words: Vec<String> = words
+            .iter()
+            .map(|word| format!("Cow::Borrowed({word:?})"))
+            .collect();
+        writeln!(
+            output,
+            "        ({character:?}, CodeWord::from_static({code_word:?}, {:?}, {:?}, &[{}])),",
+            code_word.to_lowercase(),
+            code_word.to_uppercase(),
+            words.join(", "),
+        )
+        .unwrap();
+    }
+    writeln!(output, "    ],").unwrap();
+    writeln!(output, "    index: &[").unwrap();
+    for (key, character) in &index {
+        writeln!(output, "        ({key:?}, {character:?}),").unwrap();
+    }
+    writeln!(output, "    ],").unwrap();
+    writeln!(output, "}};\n").unwrap();
+}
+
+fn write_pronunciations(output: &mut String) {
+    writeln!(
+        output,
+        "pub static PRONUNCIATIONS: [(&str, &str, &str); {}] = [",
+        PRONUNCIATIONS.len()
+    )
+    .unwrap();
+    for entry in PRONUNCIATIONS {
+        writeln!(output, "    {entry:?},").unwrap();
+    }
+    writeln!(output, "];").unwrap();
+}
+
+// Normalize a code word for lookup, ignoring case and word separators. This
+// must match `index_key()` in the crate.
+fn index_key(code_word: &str) -> String {
+    code_word
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
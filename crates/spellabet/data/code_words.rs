@@ -0,0 +1,1543 @@
+// The code word tables of the built-in spelling alphabets and vocabularies.
+//
+// This file is read by the build script rather than compiled into the crate.
+// It generates a sorted static table of `CodeWord`s from each of these tables,
+// so that code words are looked up directly, without building a map at runtime.
+//
+// Code words of more than one word mark where each word starts. Words that are
+// written apart are separated by a space, like "Inférieur à", and words that
+// are written joined are separated by a `|`, like "Double|Quote" for
+// "DoubleQuote".
+
+// English
+pub const DEFAULT_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Zero"),
+    ('1', "One"),
+    ('2', "Two"),
+    ('3', "Three"),
+    ('4', "Four"),
+    ('5', "Five"),
+    ('6', "Six"),
+    ('7', "Seven"),
+    ('8', "Eight"),
+    ('9', "Nine"),
+    (' ', "Space"),
+    ('!', "Exclamation"),
+    ('"', "Double|Quote"),
+    ('#', "Hash"),
+    ('$', "Dollars"),
+    ('%', "Percent"),
+    ('&', "Ampersand"),
+    ('(', "Left|Parens"),
+    (')', "Right|Parens"),
+    ('*', "Asterisk"),
+    ('+', "Plus"),
+    (',', "Comma"),
+    ('-', "Dash"),
+    ('.', "Period"),
+    ('/', "Fore|Slash"),
+    (':', "Colon"),
+    (';', "Semi|Colon"),
+    ('<', "Less|Than"),
+    ('=', "Equals"),
+    ('>', "Greater|Than"),
+    ('?', "Question"),
+    ('@', "At"),
+    ('[', "Left|Bracket"),
+    ('\'', "Single|Quote"),
+    ('\\', "Back|Slash"),
+    (']', "Right|Bracket"),
+    ('^', "Caret"),
+    ('_', "Underscore"),
+    ('`', "Backtick"),
+    ('{', "Left|Brace"),
+    ('|', "Pipe"),
+    ('}', "Right|Brace"),
+    ('~', "Tilde"),
+];
+
+// Dutch
+pub const DUTCH_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Nul"),
+    ('1', "Een"),
+    ('2', "Twee"),
+    ('3', "Drie"),
+    ('4', "Vier"),
+    ('5', "Vijf"),
+    ('6', "Zes"),
+    ('7', "Zeven"),
+    ('8', "Acht"),
+    ('9', "Negen"),
+    (' ', "Spatie"),
+    ('!', "Uitroepteken"),
+    ('"', "Aanhalingsteken"),
+    ('#', "Hekje"),
+    ('$', "Dollar"),
+    ('%', "Procent"),
+    ('&', "En|Teken"),
+    ('(', "Haakje|Openen"),
+    (')', "Haakje|Sluiten"),
+    ('*', "Sterretje"),
+    ('+', "Plus"),
+    (',', "Komma"),
+    ('-', "Streepje"),
+    ('.', "Punt"),
+    ('/', "Schuine|Streep"),
+    (':', "Dubbelepunt"),
+    (';', "Puntkomma"),
+    ('<', "Kleiner|Dan"),
+    ('=', "Is|Gelijk"),
+    ('>', "Groter|Dan"),
+    ('?', "Vraagteken"),
+    ('@', "Apenstaartje"),
+    ('[', "Blokhaak|Openen"),
+    ('\'', "Apostrof"),
+    ('\\', "Backslash"),
+    (']', "Blokhaak|Sluiten"),
+    ('^', "Dakje"),
+    ('_', "Liggend|Streepje"),
+    ('`', "Accent|Grave"),
+    ('{', "Accolade|Openen"),
+    ('|', "Verticale|Streep"),
+    ('}', "Accolade|Sluiten"),
+    ('~', "Tilde"),
+];
+
+// French
+pub const FRENCH_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Zéro"),
+    ('1', "Un"),
+    ('2', "Deux"),
+    ('3', "Trois"),
+    ('4', "Quatre"),
+    ('5', "Cinq"),
+    ('6', "Six"),
+    ('7', "Sept"),
+    ('8', "Huit"),
+    ('9', "Neuf"),
+    (' ', "Espace"),
+    ('!', "Point d'exclamation"),
+    ('"', "Guillemet"),
+    ('#', "Dièse"),
+    ('$', "Dollar"),
+    ('%', "Pour cent"),
+    ('&', "Esperluette"),
+    ('(', "Parenthèse ouvrante"),
+    (')', "Parenthèse fermante"),
+    ('*', "Astérisque"),
+    ('+', "Plus"),
+    (',', "Virgule"),
+    ('-', "Tiret"),
+    ('.', "Point"),
+    ('/', "Barre oblique"),
+    (':', "Deux-points"),
+    (';', "Point-virgule"),
+    ('<', "Inférieur à"),
+    ('=', "Égal"),
+    ('>', "Supérieur à"),
+    ('?', "Point d'interrogation"),
+    ('@', "Arobase"),
+    ('[', "Crochet ouvrant"),
+    ('\'', "Apostrophe"),
+    ('\\', "Barre oblique inversée"),
+    (']', "Crochet fermant"),
+    ('^', "Circonflexe"),
+    ('_', "Tiret bas"),
+    ('`', "Accent grave"),
+    ('{', "Accolade ouvrante"),
+    ('|', "Barre verticale"),
+    ('}', "Accolade fermante"),
+    ('~', "Tilde"),
+];
+
+// German
+pub const GERMAN_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Null"),
+    ('1', "Eins"),
+    ('2', "Zwei"),
+    ('3', "Drei"),
+    ('4', "Vier"),
+    ('5', "Fünf"),
+    ('6', "Sechs"),
+    ('7', "Sieben"),
+    ('8', "Acht"),
+    ('9', "Neun"),
+    (' ', "Leerzeichen"),
+    ('!', "Ausrufezeichen"),
+    ('"', "Anführungszeichen"),
+    ('#', "Raute"),
+    ('$', "Dollar"),
+    ('%', "Prozent"),
+    ('&', "Und|Zeichen"),
+    ('(', "Klammer|Auf"),
+    (')', "Klammer|Zu"),
+    ('*', "Stern"),
+    ('+', "Plus"),
+    (',', "Komma"),
+    ('-', "Bindestrich"),
+    ('.', "Punkt"),
+    ('/', "Schrägstrich"),
+    (':', "Doppelpunkt"),
+    (';', "Semikolon"),
+    ('<', "Kleiner|Als"),
+    ('=', "Gleich"),
+    ('>', "Größer|Als"),
+    ('?', "Fragezeichen"),
+    ('@', "At"),
+    ('[', "Eckige|Klammer|Auf"),
+    ('\'', "Apostroph"),
+    ('\\', "Backslash"),
+    (']', "Eckige|Klammer|Zu"),
+    ('^', "Zirkumflex"),
+    ('_', "Unterstrich"),
+    ('`', "Gravis"),
+    ('{', "Geschweifte|Klammer|Auf"),
+    ('|', "Senkrechter|Strich"),
+    ('}', "Geschweifte|Klammer|Zu"),
+    ('~', "Tilde"),
+];
+
+// Italian
+pub const ITALIAN_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Zero"),
+    ('1', "Uno"),
+    ('2', "Due"),
+    ('3', "Tre"),
+    ('4', "Quattro"),
+    ('5', "Cinque"),
+    ('6', "Sei"),
+    ('7', "Sette"),
+    ('8', "Otto"),
+    ('9', "Nove"),
+    (' ', "Spazio"),
+    ('!', "Punto|Esclamativo"),
+    ('"', "Virgolette"),
+    ('#', "Cancelletto"),
+    ('$', "Dollaro"),
+    ('%', "Percento"),
+    ('&', "E|Commerciale"),
+    ('(', "Parentesi|Aperta"),
+    (')', "Parentesi|Chiusa"),
+    ('*', "Asterisco"),
+    ('+', "Più"),
+    (',', "Virgola"),
+    ('-', "Trattino"),
+    ('.', "Punto"),
+    ('/', "Barra"),
+    (':', "Due|Punti"),
+    (';', "Punto|E|Virgola"),
+    ('<', "Minore|Di"),
+    ('=', "Uguale"),
+    ('>', "Maggiore|Di"),
+    ('?', "Punto|Interrogativo"),
+    ('@', "Chiocciola"),
+    ('[', "Quadra|Aperta"),
+    ('\'', "Apostrofo"),
+    ('\\', "Barra|Rovesciata"),
+    (']', "Quadra|Chiusa"),
+    ('^', "Circonflesso"),
+    ('_', "Trattino|Basso"),
+    ('`', "Accento|Grave"),
+    ('{', "Graffa|Aperta"),
+    ('|', "Barra|Verticale"),
+    ('}', "Graffa|Chiusa"),
+    ('~', "Tilde"),
+];
+
+// Spanish
+pub const SPANISH_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Cero"),
+    ('1', "Uno"),
+    ('2', "Dos"),
+    ('3', "Tres"),
+    ('4', "Cuatro"),
+    ('5', "Cinco"),
+    ('6', "Seis"),
+    ('7', "Siete"),
+    ('8', "Ocho"),
+    ('9', "Nueve"),
+    (' ', "Espacio"),
+    ('!', "Exclamación"),
+    ('"', "Comillas"),
+    ('#', "Almohadilla"),
+    ('$', "Dólar"),
+    ('%', "Porcentaje"),
+    ('&', "Et"),
+    ('(', "Abre|Paréntesis"),
+    (')', "Cierra|Paréntesis"),
+    ('*', "Asterisco"),
+    ('+', "Más"),
+    (',', "Coma"),
+    ('-', "Guion"),
+    ('.', "Punto"),
+    ('/', "Barra"),
+    (':', "Dos|Puntos"),
+    (';', "Punto|Y|Coma"),
+    ('<', "Menor|Que"),
+    ('=', "Igual"),
+    ('>', "Mayor|Que"),
+    ('?', "Interrogación"),
+    ('@', "Arroba"),
+    ('[', "Abre|Corchete"),
+    ('\'', "Apóstrofo"),
+    ('\\', "Barra|Invertida"),
+    (']', "Cierra|Corchete"),
+    ('^', "Circunflejo"),
+    ('_', "Guion|Bajo"),
+    ('`', "Acento|Grave"),
+    ('{', "Abre|Llave"),
+    ('|', "Barra|Vertical"),
+    ('}', "Cierra|Llave"),
+    ('~', "Virgulilla"),
+];
+
+// Arabic
+pub const ARABIC_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "صفر"),
+    ('1', "واحد"),
+    ('2', "اثنان"),
+    ('3', "ثلاثة"),
+    ('4', "أربعة"),
+    ('5', "خمسة"),
+    ('6', "ستة"),
+    ('7', "سبعة"),
+    ('8', "ثمانية"),
+    ('9', "تسعة"),
+    (' ', "مسافة"),
+    ('!', "علامة تعجب"),
+    ('"', "علامة تنصيص"),
+    ('#', "شباك"),
+    ('$', "دولار"),
+    ('%', "بالمئة"),
+    ('&', "علامة العطف"),
+    ('(', "قوس فتح"),
+    (')', "قوس إغلاق"),
+    ('*', "نجمة"),
+    ('+', "زائد"),
+    (',', "فاصلة"),
+    ('-', "شرطة"),
+    ('.', "نقطة"),
+    ('/', "شرطة مائلة"),
+    (':', "نقطتان"),
+    (';', "فاصلة منقوطة"),
+    ('<', "أصغر من"),
+    ('=', "يساوي"),
+    ('>', "أكبر من"),
+    ('?', "علامة استفهام"),
+    ('@', "علامة آت"),
+    ('[', "قوس مربع فتح"),
+    ('\'', "فاصلة عليا"),
+    ('\\', "شرطة مائلة عكسية"),
+    (']', "قوس مربع إغلاق"),
+    ('^', "علامة إقحام"),
+    ('_', "شرطة سفلية"),
+    ('`', "فاصلة عليا مقلوبة"),
+    ('{', "قوس معقوص فتح"),
+    ('|', "خط عمودي"),
+    ('}', "قوس معقوص إغلاق"),
+    ('~', "تيلدا"),
+];
+
+// Bulgarian
+pub const BULGARIAN_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Нула"),
+    ('1', "Едно"),
+    ('2', "Две"),
+    ('3', "Три"),
+    ('4', "Четири"),
+    ('5', "Пет"),
+    ('6', "Шест"),
+    ('7', "Седем"),
+    ('8', "Осем"),
+    ('9', "Девет"),
+    (' ', "Интервал"),
+    ('!', "Удивителен знак"),
+    ('"', "Кавички"),
+    ('#', "Диез"),
+    ('$', "Долар"),
+    ('%', "Процент"),
+    ('&', "Амперсанд"),
+    ('(', "Отваряща скоба"),
+    (')', "Затваряща скоба"),
+    ('*', "Звездичка"),
+    ('+', "Плюс"),
+    (',', "Запетая"),
+    ('-', "Тире"),
+    ('.', "Точка"),
+    ('/', "Наклонена черта"),
+    (':', "Двоеточие"),
+    (';', "Точка и запетая"),
+    ('<', "По-малко"),
+    ('=', "Равно"),
+    ('>', "По-голямо"),
+    ('?', "Въпросителен знак"),
+    ('@', "Кльомба"),
+    ('[', "Отваряща квадратна скоба"),
+    ('\'', "Апостроф"),
+    ('\\', "Обратна наклонена черта"),
+    (']', "Затваряща квадратна скоба"),
+    ('^', "Карета"),
+    ('_', "Долна черта"),
+    ('`', "Обратен апостроф"),
+    ('{', "Отваряща фигурна скоба"),
+    ('|', "Вертикална черта"),
+    ('}', "Затваряща фигурна скоба"),
+    ('~', "Тилда"),
+];
+
+// Greek
+pub const GREEK_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Μηδέν"),
+    ('1', "Ένα"),
+    ('2', "Δύο"),
+    ('3', "Τρία"),
+    ('4', "Τέσσερα"),
+    ('5', "Πέντε"),
+    ('6', "Έξι"),
+    ('7', "Επτά"),
+    ('8', "Οκτώ"),
+    ('9', "Εννέα"),
+    (' ', "Κενό"),
+    ('!', "Θαυμαστικό"),
+    ('"', "Εισαγωγικά"),
+    ('#', "Δίεση"),
+    ('$', "Δολάριο"),
+    ('%', "Τοις εκατό"),
+    ('&', "Συμπλεκτικό"),
+    ('(', "Αριστερή παρένθεση"),
+    (')', "Δεξιά παρένθεση"),
+    ('*', "Αστερίσκος"),
+    ('+', "Συν"),
+    (',', "Κόμμα"),
+    ('-', "Παύλα"),
+    ('.', "Τελεία"),
+    ('/', "Κάθετος"),
+    (':', "Άνω κάτω τελεία"),
+    (';', "Ερωτηματικό"),
+    ('<', "Μικρότερο από"),
+    ('=', "Ίσον"),
+    ('>', "Μεγαλύτερο από"),
+    ('?', "Λατινικό ερωτηματικό"),
+    ('@', "Παπάκι"),
+    ('[', "Αριστερή αγκύλη"),
+    ('\'', "Απόστροφος"),
+    ('\\', "Ανάστροφη κάθετος"),
+    (']', "Δεξιά αγκύλη"),
+    ('^', "Καρέ"),
+    ('_', "Κάτω παύλα"),
+    ('`', "Βαρεία"),
+    ('{', "Αριστερό άγκιστρο"),
+    ('|', "Κατακόρυφη γραμμή"),
+    ('}', "Δεξί άγκιστρο"),
+    ('~', "Περισπωμένη"),
+];
+
+// Hebrew
+pub const HEBREW_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "אפס"),
+    ('1', "אחת"),
+    ('2', "שתיים"),
+    ('3', "שלוש"),
+    ('4', "ארבע"),
+    ('5', "חמש"),
+    ('6', "שש"),
+    ('7', "שבע"),
+    ('8', "שמונה"),
+    ('9', "תשע"),
+    (' ', "רווח"),
+    ('!', "סימן קריאה"),
+    ('"', "מירכאות"),
+    ('#', "סולמית"),
+    ('$', "דולר"),
+    ('%', "אחוז"),
+    ('&', "אמפרסנד"),
+    ('(', "סוגר פותח"),
+    (')', "סוגר סוגר"),
+    ('*', "כוכבית"),
+    ('+', "פלוס"),
+    (',', "פסיק"),
+    ('-', "מקף"),
+    ('.', "נקודה"),
+    ('/', "לוכסן"),
+    (':', "נקודתיים"),
+    (';', "נקודה ופסיק"),
+    ('<', "קטן מ"),
+    ('=', "שווה"),
+    ('>', "גדול מ"),
+    ('?', "סימן שאלה"),
+    ('@', "שטרודל"),
+    ('[', "סוגר מרובע פותח"),
+    ('\'', "גרש"),
+    ('\\', "לוכסן הפוך"),
+    (']', "סוגר מרובע סוגר"),
+    ('^', "גג"),
+    ('_', "קו תחתון"),
+    ('`', "גרש הפוך"),
+    ('{', "סוגר מסולסל פותח"),
+    ('|', "קו אנכי"),
+    ('}', "סוגר מסולסל סוגר"),
+    ('~', "טילדה"),
+];
+
+// Japanese
+pub const JAPANESE_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "ゼロ"),
+    ('1', "イチ"),
+    ('2', "ニ"),
+    ('3', "サン"),
+    ('4', "ヨン"),
+    ('5', "ゴ"),
+    ('6', "ロク"),
+    ('7', "ナナ"),
+    ('8', "ハチ"),
+    ('9', "キュウ"),
+    (' ', "スペース"),
+    ('!', "感嘆符"),
+    ('"', "二重引用符"),
+    ('#', "シャープ"),
+    ('$', "ドル"),
+    ('%', "パーセント"),
+    ('&', "アンド"),
+    ('(', "左丸括弧"),
+    (')', "右丸括弧"),
+    ('*', "アスタリスク"),
+    ('+', "プラス"),
+    (',', "カンマ"),
+    ('-', "ハイフン"),
+    ('.', "ピリオド"),
+    ('/', "スラッシュ"),
+    (':', "コロン"),
+    (';', "セミコロン"),
+    ('<', "小なり"),
+    ('=', "イコール"),
+    ('>', "大なり"),
+    ('?', "疑問符"),
+    ('@', "アットマーク"),
+    ('[', "左角括弧"),
+    ('\'', "アポストロフィ"),
+    ('\\', "バックスラッシュ"),
+    (']', "右角括弧"),
+    ('^', "キャレット"),
+    ('_', "アンダースコア"),
+    ('`', "バッククォート"),
+    ('{', "左波括弧"),
+    ('|', "縦線"),
+    ('}', "右波括弧"),
+    ('~', "チルダ"),
+];
+
+// Korean
+pub const KOREAN_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "공"),
+    ('1', "하나"),
+    ('2', "둘"),
+    ('3', "셋"),
+    ('4', "넷"),
+    ('5', "다섯"),
+    ('6', "여섯"),
+    ('7', "일곱"),
+    ('8', "여덟"),
+    ('9', "아홉"),
+    (' ', "공백"),
+    ('!', "느낌표"),
+    ('"', "큰따옴표"),
+    ('#', "샵"),
+    ('$', "달러"),
+    ('%', "퍼센트"),
+    ('&', "앰퍼샌드"),
+    ('(', "여는 괄호"),
+    (')', "닫는 괄호"),
+    ('*', "별표"),
+    ('+', "더하기"),
+    (',', "쉼표"),
+    ('-', "하이픈"),
+    ('.', "마침표"),
+    ('/', "빗금"),
+    (':', "쌍점"),
+    (';', "쌍반점"),
+    ('<', "보다 작음"),
+    ('=', "등호"),
+    ('>', "보다 큼"),
+    ('?', "물음표"),
+    ('@', "골뱅이"),
+    ('[', "여는 대괄호"),
+    ('\'', "작은따옴표"),
+    ('\\', "역빗금"),
+    (']', "닫는 대괄호"),
+    ('^', "캐럿"),
+    ('_', "밑줄"),
+    ('`', "억음 부호"),
+    ('{', "여는 중괄호"),
+    ('|', "세로줄"),
+    ('}', "닫는 중괄호"),
+    ('~', "물결표"),
+];
+
+// Russian
+pub const RUSSIAN_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Ноль"),
+    ('1', "Один"),
+    ('2', "Два"),
+    ('3', "Три"),
+    ('4', "Четыре"),
+    ('5', "Пять"),
+    ('6', "Шесть"),
+    ('7', "Семь"),
+    ('8', "Восемь"),
+    ('9', "Девять"),
+    (' ', "Пробел"),
+    ('!', "Восклицательный знак"),
+    ('"', "Кавычки"),
+    ('#', "Решётка"),
+    ('$', "Доллар"),
+    ('%', "Процент"),
+    ('&', "Амперсанд"),
+    ('(', "Открывающая скобка"),
+    (')', "Закрывающая скобка"),
+    ('*', "Звёздочка"),
+    ('+', "Плюс"),
+    (',', "Запятая"),
+    ('-', "Дефис"),
+    ('.', "Точка"),
+    ('/', "Косая черта"),
+    (':', "Двоеточие"),
+    (';', "Точка с запятой"),
+    ('<', "Меньше"),
+    ('=', "Равно"),
+    ('>', "Больше"),
+    ('?', "Вопросительный знак"),
+    ('@', "Собака"),
+    ('[', "Открывающая квадратная скобка"),
+    ('\'', "Апостроф"),
+    ('\\', "Обратная косая черта"),
+    (']', "Закрывающая квадратная скобка"),
+    ('^', "Циркумфлекс"),
+    ('_', "Подчёркивание"),
+    ('`', "Обратный апостроф"),
+    ('{', "Открывающая фигурная скобка"),
+    ('|', "Вертикальная черта"),
+    ('}', "Закрывающая фигурная скобка"),
+    ('~', "Тильда"),
+];
+
+// Ukrainian
+pub const UKRAINIAN_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Нуль"),
+    ('1', "Один"),
+    ('2', "Два"),
+    ('3', "Три"),
+    ('4', "Чотири"),
+    ('5', "П'ять"),
+    ('6', "Шість"),
+    ('7', "Сім"),
+    ('8', "Вісім"),
+    ('9', "Дев'ять"),
+    (' ', "Пробіл"),
+    ('!', "Знак оклику"),
+    ('"', "Лапки"),
+    ('#', "Решітка"),
+    ('$', "Долар"),
+    ('%', "Відсоток"),
+    ('&', "Амперсанд"),
+    ('(', "Відкривна дужка"),
+    (')', "Закривна дужка"),
+    ('*', "Зірочка"),
+    ('+', "Плюс"),
+    (',', "Кома"),
+    ('-', "Дефіс"),
+    ('.', "Крапка"),
+    ('/', "Скісна риска"),
+    (':', "Двокрапка"),
+    (';', "Крапка з комою"),
+    ('<', "Менше"),
+    ('=', "Дорівнює"),
+    ('>', "Більше"),
+    ('?', "Знак питання"),
+    ('@', "Равлик"),
+    ('[', "Відкривна квадратна дужка"),
+    ('\'', "Апостроф"),
+    ('\\', "Зворотна скісна риска"),
+    (']', "Закривна квадратна дужка"),
+    ('^', "Циркумфлекс"),
+    ('_', "Нижнє підкреслення"),
+    ('`', "Гравіс"),
+    ('{', "Відкривна фігурна дужка"),
+    ('|', "Вертикальна риска"),
+    ('}', "Закривна фігурна дужка"),
+    ('~', "Тильда"),
+];
+
+// Formal, following the Unicode character names
+pub const FORMAL_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Zero"),
+    ('1', "One"),
+    ('2', "Two"),
+    ('3', "Three"),
+    ('4', "Four"),
+    ('5', "Five"),
+    ('6', "Six"),
+    ('7', "Seven"),
+    ('8', "Eight"),
+    ('9', "Nine"),
+    (' ', "Space"),
+    ('!', "Exclamation|Mark"),
+    ('"', "Quotation|Mark"),
+    ('#', "Number|Sign"),
+    ('$', "Dollar|Sign"),
+    ('%', "Percent|Sign"),
+    ('&', "Ampersand"),
+    ('(', "Left|Parenthesis"),
+    (')', "Right|Parenthesis"),
+    ('*', "Asterisk"),
+    ('+', "Plus|Sign"),
+    (',', "Comma"),
+    ('-', "Hyphen|Minus"),
+    ('.', "Full|Stop"),
+    ('/', "Solidus"),
+    (':', "Colon"),
+    (';', "Semicolon"),
+    ('<', "Less|Than|Sign"),
+    ('=', "Equals|Sign"),
+    ('>', "Greater|Than|Sign"),
+    ('?', "Question|Mark"),
+    ('@', "Commercial|At"),
+    ('[', "Left|Square|Bracket"),
+    ('\'', "Apostrophe"),
+    ('\\', "Reverse|Solidus"),
+    (']', "Right|Square|Bracket"),
+    ('^', "Circumflex|Accent"),
+    ('_', "Low|Line"),
+    ('`', "Grave|Accent"),
+    ('{', "Left|Curly|Bracket"),
+    ('|', "Vertical|Line"),
+    ('}', "Right|Curly|Bracket"),
+    ('~', "Tilde"),
+];
+
+// Military radiotelephony procedure words
+pub const MILITARY_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Zero"),
+    ('1', "One"),
+    ('2', "Two"),
+    ('3', "Tree"),
+    ('4', "Fower"),
+    ('5', "Fife"),
+    ('6', "Six"),
+    ('7', "Seven"),
+    ('8', "Eight"),
+    ('9', "Niner"),
+    (' ', "Space"),
+    ('!', "Exclamation"),
+    ('"', "Quote"),
+    ('#', "Hash"),
+    ('$', "Dollar"),
+    ('%', "Percent"),
+    ('&', "Ampersand"),
+    ('(', "Bracket|Open"),
+    (')', "Bracket|Close"),
+    ('*', "Asterisk"),
+    ('+', "Plus"),
+    (',', "Comma"),
+    ('-', "Hyphen"),
+    ('.', "Stop"),
+    ('/', "Stroke"),
+    (':', "Colon"),
+    (';', "Semicolon"),
+    ('<', "Less|Than"),
+    ('=', "Equals"),
+    ('>', "Greater|Than"),
+    ('?', "Query"),
+    ('@', "At"),
+    ('[', "Square|Bracket|Open"),
+    ('\'', "Apostrophe"),
+    ('\\', "Backslash"),
+    (']', "Square|Bracket|Close"),
+    ('^', "Caret"),
+    ('_', "Underscore"),
+    ('`', "Backtick"),
+    ('{', "Brace|Open"),
+    ('|', "Pipe"),
+    ('}', "Brace|Close"),
+    ('~', "Tilde"),
+];
+
+// Programmer jargon
+pub const PROGRAMMER_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
+    ('0', "Zero"),
+    ('1', "One"),
+    ('2', "Two"),
+    ('3', "Three"),
+    ('4', "Four"),
+    ('5', "Five"),
+    ('6', "Six"),
+    ('7', "Seven"),
+    ('8', "Eight"),
+    ('9', "Nine"),
+    (' ', "Space"),
+    ('!', "Bang"),
+    ('"', "Quote"),
+    ('#', "Hash"),
+    ('$', "Dollar"),
+    ('%', "Percent"),
+    ('&', "Amp"),
+    ('(', "Open|Paren"),
+    (')', "Close|Paren"),
+    ('*', "Splat"),
+    ('+', "Plus"),
+    (',', "Comma"),
+    ('-', "Dash"),
+    ('.', "Dot"),
+    ('/', "Slash"),
+    (':', "Colon"),
+    (';', "Semi"),
+    ('<', "Less|Than"),
+    ('=', "Equals"),
+    ('>', "Greater|Than"),
+    ('?', "Query"),
+    ('@', "At"),
+    ('[', "Open|Bracket"),
+    ('\'', "Tick"),
+    ('\\', "Backslash"),
+    (']', "Close|Bracket"),
+    ('^', "Caret"),
+    ('_', "Underscore"),
+    ('`', "Backtick"),
+    ('{', "Open|Brace"),
+    ('|', "Pipe"),
+    ('}', "Close|Brace"),
+    ('~', "Tilde"),
+];
+
+// Arabic letter names
+// Keys are the base letters; contextual presentation forms are folded into
+// them during lookup. Names of more than one word are separated by spaces, as
+// the script has no letter case to mark word boundaries.
+pub const ARABIC_ALPHABET: [(char, &str); 36] = [
+    ('ء', "همزة"),
+    ('آ', "ألف مد"),
+    ('أ', "همزة على ألف"),
+    ('ؤ', "همزة على واو"),
+    ('إ', "همزة تحت ألف"),
+    ('ئ', "همزة على ياء"),
+    ('ا', "ألف"),
+    ('ب', "باء"),
+    ('ة', "تاء مربوطة"),
+    ('ت', "تاء"),
+    ('ث', "ثاء"),
+    ('ج', "جيم"),
+    ('ح', "حاء"),
+    ('خ', "خاء"),
+    ('د', "دال"),
+    ('ذ', "ذال"),
+    ('ر', "راء"),
+    ('ز', "زاي"),
+    ('س', "سين"),
+    ('ش', "شين"),
+    ('ص', "صاد"),
+    ('ض', "ضاد"),
+    ('ط', "طاء"),
+    ('ظ', "ظاء"),
+    ('ع', "عين"),
+    ('غ', "غين"),
+    ('ف', "فاء"),
+    ('ق', "قاف"),
+    ('ك', "كاف"),
+    ('ل', "لام"),
+    ('م', "ميم"),
+    ('ن', "نون"),
+    ('ه', "هاء"),
+    ('و', "واو"),
+    ('ى', "ألف مقصورة"),
+    ('ي', "ياء"),
+];
+
+// Bulgarian
+pub const BULGARIAN_ALPHABET: [(char, &str); 30] = [
+    ('а', "Антон"),
+    ('б', "Борис"),
+    ('в', "Васил"),
+    ('г', "Георги"),
+    ('д', "Димитър"),
+    ('е', "Елена"),
+    ('ж', "Живка"),
+    ('з', "Зорка"),
+    ('и', "Иван"),
+    ('й', "Иван кратко"),
+    ('к', "Константин"),
+    ('л', "Любен"),
+    ('м', "Мария"),
+    ('н', "Никола"),
+    ('о', "Огнян"),
+    ('п', "Петър"),
+    ('р', "Радко"),
+    ('с', "Стоян"),
+    ('т', "Тодор"),
+    ('у', "Улица"),
+    ('ф', "Фотьо"),
+    ('х', "Христо"),
+    ('ц', "Цанко"),
+    ('ч', "Чавдар"),
+    ('ш', "Шабла"),
+    ('щ', "Щурец"),
+    ('ъ', "Ер голям"),
+    ('ь', "Ер малък"),
+    ('ю', "Юмрук"),
+    ('я', "Ямбол"),
+];
+
+// Greek
+// The tonos and dialytika entries name the accents of decomposed vowels.
+pub const GREEK_ALPHABET: [(char, &str); 26] = [
+    ('α', "Αστήρ"),
+    ('β', "Βύρων"),
+    ('γ', "Γαλή"),
+    ('δ', "Δόξα"),
+    ('ε', "Ερμής"),
+    ('ζ', "Ζευς"),
+    ('η', "Ηρώ"),
+    ('θ', "Θεά"),
+    ('ι', "Ιδέα"),
+    ('κ', "Κενόν"),
+    ('λ', "Λάμα"),
+    ('μ', "Μαρία"),
+    ('ν', "Νίκη"),
+    ('ξ', "Ξέρξης"),
+    ('ο', "Οδυσσεύς"),
+    ('π', "Πέτρος"),
+    ('ρ', "Ρήγας"),
+    ('σ', "Σωτήρ"),
+    ('τ', "Τίγρης"),
+    ('υ', "Ύμνος"),
+    ('φ', "Φωφώ"),
+    ('χ', "Χαρά"),
+    ('ψ', "Ψυχή"),
+    ('ω', "Ωμέγα"),
+    ('\u{0384}', "Τόνος"),
+    ('\u{00a8}', "Διαλυτικά"),
+];
+
+// Hebrew letter names
+// Keys are the base letters; final forms are folded into them during lookup.
+pub const HEBREW_ALPHABET: [(char, &str); 22] = [
+    ('א', "אלף"),
+    ('ב', "בית"),
+    ('ג', "גימל"),
+    ('ד', "דלת"),
+    ('ה', "הא"),
+    ('ו', "וו"),
+    ('ז', "זין"),
+    ('ח', "חית"),
+    ('ט', "טית"),
+    ('י', "יוד"),
+    ('כ', "כף"),
+    ('ל', "למד"),
+    ('מ', "מם"),
+    ('נ', "נון"),
+    ('ס', "סמך"),
+    ('ע', "עין"),
+    ('פ', "פא"),
+    ('צ', "צדי"),
+    ('ק', "קוף"),
+    ('ר', "ריש"),
+    ('ש', "שין"),
+    ('ת', "תו"),
+];
+
+// International Civil Aviation Organization (ICAO) draft, 1951
+// Drafted for speakers of English, French, and Spanish.
+pub const ICAO_1951_DRAFT_ALPHABET: [(char, &str); 26] = [
+    ('a', "Ana"),
+    ('b', "Brazil"),
+    ('c', "Coco"),
+    ('d', "Dado"),
+    ('e', "Elsa"),
+    ('f', "Fiesta"),
+    ('g', "Gato"),
+    ('h', "Hombre"),
+    ('i', "India"),
+    ('j', "Julio"),
+    ('k', "Kilo"),
+    ('l', "Luis"),
+    ('m', "Mama"),
+    ('n', "Normal"),
+    ('o', "Oriente"),
+    ('p', "Pedro"),
+    ('q', "Queen"),
+    ('r', "Rosa"),
+    ('s', "Sara"),
+    ('t', "Tomas"),
+    ('u', "Ursula"),
+    ('v', "Victor"),
+    ('w', "Whisky"),
+    ('x', "Equis"),
+    ('y', "Yolanda"),
+    ('z', "Zeta"),
+];
+
+// International Telecommunication Union (ITU), 1927
+// International Radiotelegraph Convention of Washington
+pub const ITU_1927_ALPHABET: [(char, &str); 26] = [
+    ('a', "Amsterdam"),
+    ('b', "Baltimore"),
+    ('c', "Canada"),
+    ('d', "Denmark"),
+    ('e', "Eddystone"),
+    ('f', "Francisco"),
+    ('g', "Gibraltar"),
+    ('h', "Hanover"),
+    ('i', "Italy"),
+    ('j', "Jerusalem"),
+    ('k', "Kimberley"),
+    ('l', "Liverpool"),
+    ('m', "Madagascar"),
+    ('n', "Neufchatel"),
+    ('o', "Ontario"),
+    ('p', "Portugal"),
+    ('q', "Quebec"),
+    ('r', "Rivoli"),
+    ('s', "Santiago"),
+    ('t', "Tokio"),
+    ('u', "Uruguay"),
+    ('v', "Victoria"),
+    ('w', "Washington"),
+    ('x', "Xantippe"),
+    ('y', "Yokohama"),
+    ('z', "Zululand"),
+];
+
+// International Telecommunication Union (ITU), 1938
+// General Radiocommunication Regulations of Cairo
+pub const ITU_1938_ALPHABET: [(char, &str); 26] = [
+    ('a', "Amsterdam"),
+    ('b', "Baltimore"),
+    ('c', "Casablanca"),
+    ('d', "Danemark"),
+    ('e', "Edison"),
+    ('f', "Florida"),
+    ('g', "Gallipoli"),
+    ('h', "Havana"),
+    ('i', "Italia"),
+    ('j', "Jerusalem"),
+    ('k', "Kilogramme"),
+    ('l', "Liverpool"),
+    ('m', "Madagaskar"),
+    ('n', "New|York"),
+    ('o', "Oslo"),
+    ('p', "Paris"),
+    ('q', "Quebec"),
+    ('r', "Roma"),
+    ('s', "Santiago"),
+    ('t', "Tripoli"),
+    ('u', "Upsala"),
+    ('v', "Valencia"),
+    ('w', "Washington"),
+    ('x', "Xanthippe"),
+    ('y', "Yokohama"),
+    ('z', "Zurich"),
+];
+
+// Joint Army/Navy (JAN)
+pub const JAN_ALPHABET: [(char, &str); 26] = [
+    ('a', "Able"),
+    ('b', "Baker"),
+    ('c', "Charlie"),
+    ('d', "Dog"),
+    ('e', "Easy"),
+    ('f', "Fox"),
+    ('g', "George"),
+    ('h', "How"),
+    ('i', "Item"),
+    ('j', "Jig"),
+    ('k', "King"),
+    ('l', "Love"),
+    ('m', "Mike"),
+    ('n', "Nan"),
+    ('o', "Oboe"),
+    ('p', "Peter"),
+    ('q', "Queen"),
+    ('r', "Roger"),
+    ('s', "Sugar"),
+    ('t', "Tare"),
+    ('u', "Uncle"),
+    ('v', "Victor"),
+    ('w', "William"),
+    ('x', "X-ray"),
+    ('y', "Yoke"),
+    ('z', "Zebra"),
+];
+
+// Korean Hangul jamo names
+// Keys are compatibility jamo; syllables are decomposed into jamo on lookup.
+pub const KOREAN_ALPHABET: [(char, &str); 51] = [
+    ('ㄱ', "기역"),
+    ('ㄲ', "쌍기역"),
+    ('ㄳ', "기역시옷"),
+    ('ㄴ', "니은"),
+    ('ㄵ', "니은지읒"),
+    ('ㄶ', "니은히읗"),
+    ('ㄷ', "디귿"),
+    ('ㄸ', "쌍디귿"),
+    ('ㄹ', "리을"),
+    ('ㄺ', "리을기역"),
+    ('ㄻ', "리을미음"),
+    ('ㄼ', "리을비읍"),
+    ('ㄽ', "리을시옷"),
+    ('ㄾ', "리을티읕"),
+    ('ㄿ', "리을피읖"),
+    ('ㅀ', "리을히읗"),
+    ('ㅁ', "미음"),
+    ('ㅂ', "비읍"),
+    ('ㅃ', "쌍비읍"),
+    ('ㅄ', "비읍시옷"),
+    ('ㅅ', "시옷"),
+    ('ㅆ', "쌍시옷"),
+    ('ㅇ', "이응"),
+    ('ㅈ', "지읒"),
+    ('ㅉ', "쌍지읒"),
+    ('ㅊ', "치읓"),
+    ('ㅋ', "키읔"),
+    ('ㅌ', "티읕"),
+    ('ㅍ', "피읖"),
+    ('ㅎ', "히읗"),
+    ('ㅏ', "아"),
+    ('ㅐ', "애"),
+    ('ㅑ', "야"),
+    ('ㅒ', "얘"),
+    ('ㅓ', "어"),
+    ('ㅔ', "에"),
+    ('ㅕ', "여"),
+    ('ㅖ', "예"),
+    ('ㅗ', "오"),
+    ('ㅘ', "와"),
+    ('ㅙ', "왜"),
+    ('ㅚ', "외"),
+    ('ㅛ', "요"),
+    ('ㅜ', "우"),
+    ('ㅝ', "워"),
+    ('ㅞ', "웨"),
+    ('ㅟ', "위"),
+    ('ㅠ', "유"),
+    ('ㅡ', "으"),
+    ('ㅢ', "의"),
+    ('ㅣ', "이"),
+];
+
+// Los Angeles Police Department (LAPD)
+// Association of Public-Safety Communications Officials-International (APCO)
+pub const LAPD_ALPHABET: [(char, &str); 27] = [
+    ('a', "Adam"),
+    ('b', "Boy"),
+    ('c', "Charles"),
+    ('d', "David"),
+    ('e', "Edward"),
+    ('f', "Frank"),
+    ('g', "George"),
+    ('h', "Henry"),
+    ('i', "Ida"),
+    ('j', "John"),
+    ('k', "King"),
+    ('l', "Lincoln"),
+    ('m', "Mary"),
+    ('n', "Nora"),
+    ('o', "Ocean"),
+    ('p', "Paul"),
+    ('q', "Queen"),
+    ('r', "Robert"),
+    ('s', "Sam"),
+    ('t', "Tom"),
+    ('u', "Union"),
+    ('v', "Victor"),
+    ('w', "William"),
+    ('x', "X-ray"),
+    ('y', "Young"),
+    ('z', "Zebra"),
+    ('9', "Niner"),
+];
+
+// North Atlantic Treaty Organization (NATO)
+// International Civil Aviation Organization (ICAO)
+// International Radiotelephony Spelling Alphabet (IRSA)
+pub const NATO_ALPHABET: [(char, &str); 30] = [
+    ('a', "Alfa"),
+    ('b', "Bravo"),
+    ('c', "Charlie"),
+    ('d', "Delta"),
+    ('e', "Echo"),
+    ('f', "Foxtrot"),
+    ('g', "Golf"),
+    ('h', "Hotel"),
+    ('i', "India"),
+    ('j', "Juliett"),
+    ('k', "Kilo"),
+    ('l', "Lima"),
+    ('m', "Mike"),
+    ('n', "November"),
+    ('o', "Oscar"),
+    ('p', "Papa"),
+    ('q', "Quebec"),
+    ('r', "Romeo"),
+    ('s', "Sierra"),
+    ('t', "Tango"),
+    ('u', "Uniform"),
+    ('v', "Victor"),
+    ('w', "Whiskey"),
+    ('x', "X-ray"),
+    ('y', "Yankee"),
+    ('z', "Zulu"),
+    ('3', "Tree"),
+    ('4', "Fower"),
+    ('5', "Fife"),
+    ('9', "Niner"),
+];
+
+// International Civil Aviation Organization (ICAO) pronunciations
+// Each code word is paired with its respelling and its IPA transcription.
+pub const PRONUNCIATIONS: [(&str, &str, &str); 36] = [
+    ("Alfa", "AL FAH", "ˈælfɑ"),
+    ("Bravo", "BRAH VOH", "ˈbrɑːˈvo"),
+    ("Charlie", "CHAR LEE", "ˈtʃɑːli"),
+    ("Delta", "DELL TAH", "ˈdeltɑ"),
+    ("Echo", "ECK OH", "ˈeko"),
+    ("Eight", "AIT", "eɪt"),
+    ("Fife", "FIFE", "faɪf"),
+    ("Fower", "FOW ER", "ˈfoʊ.ər"),
+    ("Foxtrot", "FOKS TROT", "ˈfɔkstrɔt"),
+    ("Golf", "GOLF", "ɡʌlf"),
+    ("Hotel", "HOH TELL", "hoːˈtel"),
+    ("India", "IN DEE AH", "ˈindiˑɑ"),
+    ("Juliett", "JEW LEE ETT", "ˈdʒuːliˑˈet"),
+    ("Kilo", "KEY LOH", "ˈkiːlo"),
+    ("Lima", "LEE MAH", "ˈliːmɑ"),
+    ("Mike", "MIKE", "mɑik"),
+    ("Niner", "NIN ER", "ˈnaɪnər"),
+    ("November", "NO VEM BER", "noˈvembə"),
+    ("One", "WUN", "wʌn"),
+    ("Oscar", "OSS CAH", "ˈɔskɑ"),
+    ("Papa", "PAH PAH", "pəˈpɑ"),
+    ("Quebec", "KEH BECK", "keˈbek"),
+    ("Romeo", "ROW ME OH", "ˈroːmiˑo"),
+    ("Seven", "SEV EN", "ˈsɛvən"),
+    ("Sierra", "SEE AIR RAH", "siˈerɑ"),
+    ("Six", "SIX", "sɪks"),
+    ("Tango", "TANG GO", "ˈtænɡo"),
+    ("Tree", "TREE", "triː"),
+    ("Two", "TOO", "tuː"),
+    ("Uniform", "YOU NEE FORM", "ˈjuːnifɔːm"),
+    ("Victor", "VIK TAH", "ˈviktɑ"),
+    ("Whiskey", "WISS KEY", "ˈwiski"),
+    ("X-ray", "ECKS RAY", "ˈeksˈrei"),
+    ("Yankee", "YANG KEY", "ˈjænki"),
+    ("Zero", "ZEE RO", "ˈziːroʊ"),
+    ("Zulu", "ZOO LOO", "ˈzuːluː"),
+];
+
+// Royal Air Force (RAF), 1924 to 1942
+pub const RAF_1924_ALPHABET: [(char, &str); 26] = [
+    ('a', "Ace"),
+    ('b', "Beer"),
+    ('c', "Charlie"),
+    ('d', "Don"),
+    ('e', "Edward"),
+    ('f', "Freddie"),
+    ('g', "George"),
+    ('h', "Harry"),
+    ('i', "Ink"),
+    ('j', "Johnnie"),
+    ('k', "King"),
+    ('l', "London"),
+    ('m', "Monkey"),
+    ('n', "Nuts"),
+    ('o', "Orange"),
+    ('p', "Pip"),
+    ('q', "Queen"),
+    ('r', "Robert"),
+    ('s', "Sugar"),
+    ('t', "Toc"),
+    ('u', "Uncle"),
+    ('v', "Vic"),
+    ('w', "William"),
+    ('x', "X-ray"),
+    ('y', "Yorker"),
+    ('z', "Zebra"),
+];
+
+// Royal Air Force (RAF), 1942 to 1943
+pub const RAF_1942_ALPHABET: [(char, &str); 26] = [
+    ('a', "Apple"),
+    ('b', "Beer"),
+    ('c', "Charlie"),
+    ('d', "Dog"),
+    ('e', "Edward"),
+    ('f', "Freddy"),
+    ('g', "George"),
+    ('h', "Harry"),
+    ('i', "In"),
+    ('j', "Jug"),
+    ('k', "King"),
+    ('l', "Love"),
+    ('m', "Mother"),
+    ('n', "Nuts"),
+    ('o', "Orange"),
+    ('p', "Peter"),
+    ('q', "Queen"),
+    ('r', "Roger"),
+    ('s', "Sugar"),
+    ('t', "Tommy"),
+    ('u', "Uncle"),
+    ('v', "Vic"),
+    ('w', "William"),
+    ('x', "X-ray"),
+    ('y', "Yoke"),
+    ('z', "Zebra"),
+];
+
+// Royal Navy
+pub const ROYAL_NAVY_ALPHABET: [(char, &str); 26] = [
+    ('a', "Apples"),
+    ('b', "Butter"),
+    ('c', "Charlie"),
+    ('d', "Duff"),
+    ('e', "Edward"),
+    ('f', "Freddy"),
+    ('g', "George"),
+    ('h', "Harry"),
+    ('i', "Ink"),
+    ('j', "Johnnie"),
+    ('k', "King"),
+    ('l', "London"),
+    ('m', "Monkey"),
+    ('n', "Nuts"),
+    ('o', "Orange"),
+    ('p', "Pudding"),
+    ('q', "Queenie"),
+    ('r', "Robert"),
+    ('s', "Sugar"),
+    ('t', "Tommy"),
+    ('u', "Uncle"),
+    ('v', "Vinegar"),
+    ('w', "William"),
+    ('x', "Xerxes"),
+    ('y', "Yellow"),
+    ('z', "Zebra"),
+];
+
+// Russian
+pub const RUSSIAN_ALPHABET: [(char, &str); 33] = [
+    ('а', "Анна"),
+    ('б', "Борис"),
+    ('в', "Василий"),
+    ('г', "Григорий"),
+    ('д', "Дмитрий"),
+    ('е', "Елена"),
+    ('ё', "Ёлка"),
+    ('ж', "Женя"),
+    ('з', "Зинаида"),
+    ('и', "Иван"),
+    ('й', "Иван краткий"),
+    ('к', "Константин"),
+    ('л', "Леонид"),
+    ('м', "Михаил"),
+    ('н', "Николай"),
+    ('о', "Ольга"),
+    ('п', "Павел"),
+    ('р', "Роман"),
+    ('с', "Семён"),
+    ('т', "Татьяна"),
+    ('у', "Ульяна"),
+    ('ф', "Фёдор"),
+    ('х', "Харитон"),
+    ('ц', "Цапля"),
+    ('ч', "Человек"),
+    ('ш', "Шура"),
+    ('щ', "Щука"),
+    ('ъ', "Твёрдый знак"),
+    ('ы', "Еры"),
+    ('ь', "Мягкий знак"),
+    ('э', "Эхо"),
+    ('ю', "Юрий"),
+    ('я', "Яков"),
+];
+
+// Ukrainian
+pub const UKRAINIAN_ALPHABET: [(char, &str); 33] = [
+    ('а', "Антон"),
+    ('б', "Богдан"),
+    ('в', "Василь"),
+    ('г', "Григорій"),
+    ('ґ', "Ґудзик"),
+    ('д', "Дмитро"),
+    ('е', "Еней"),
+    ('є', "Євген"),
+    ('ж', "Жук"),
+    ('з', "Зіна"),
+    ('и', "Индик"),
+    ('і', "Іван"),
+    ('ї', "Їжак"),
+    ('й', "Йосип"),
+    ('к', "Київ"),
+    ('л', "Леонід"),
+    ('м', "Марія"),
+    ('н', "Наталка"),
+    ('о', "Олена"),
+    ('п', "Павло"),
+    ('р', "Роман"),
+    ('с', "Степан"),
+    ('т', "Тарас"),
+    ('у', "Уляна"),
+    ('ф', "Федір"),
+    ('х', "Харитон"),
+    ('ц', "Цибуля"),
+    ('ч', "Чайка"),
+    ('ш', "Шура"),
+    ('щ', "Щука"),
+    ('ь', "М'який знак"),
+    ('ю', "Юрій"),
+    ('я', "Яків"),
+];
+
+// United States Financial Industry
+pub const US_FINANCIAL_ALPHABET: [(char, &str); 26] = [
+    ('a', "Adam"),
+    ('b', "Bob"),
+    ('c', "Carol"),
+    ('d', "David"),
+    ('e', "Eddie"),
+    ('f', "Frank"),
+    ('g', "George"),
+    ('h', "Harry"),
+    ('i', "Ike"),
+    ('j', "Jim"),
+    ('k', "Kenny"),
+    ('l', "Larry"),
+    ('m', "Mary"),
+    ('n', "Nancy"),
+    ('o', "Oliver"),
+    ('p', "Peter"),
+    ('q', "Quincy"),
+    ('r', "Roger"),
+    ('s', "Sam"),
+    ('t', "Thomas"),
+    ('u', "Uncle"),
+    ('v', "Vincent"),
+    ('w', "William"),
+    ('x', "Xavier"),
+    ('y', "Yogi"),
+    ('z', "Zachary"),
+];
+
+// United States telephone operators, 1920s
+pub const US_TELEPHONE_ALPHABET: [(char, &str); 26] = [
+    ('a', "Alice"),
+    ('b', "Bertha"),
+    ('c', "Charles"),
+    ('d', "David"),
+    ('e', "Edward"),
+    ('f', "Frank"),
+    ('g', "George"),
+    ('h', "Henry"),
+    ('i', "Ida"),
+    ('j', "James"),
+    ('k', "Kate"),
+    ('l', "Louis"),
+    ('m', "Mary"),
+    ('n', "Ned"),
+    ('o', "Oliver"),
+    ('p', "Peter"),
+    ('q', "Quaker"),
+    ('r', "Robert"),
+    ('s', "Samuel"),
+    ('t', "Thomas"),
+    ('u', "Utah"),
+    ('v', "Victor"),
+    ('w', "William"),
+    ('x', "X-ray"),
+    ('y', "Young"),
+    ('z', "Zero"),
+];
+
+// Japanese Wabun (i-ro-ha), as spoken in the form "Asahi no A"
+// Keys are hiragana; katakana is folded into hiragana during lookup. The
+// obsolete kana ゑ is omitted, as its phrase doesn't follow that form.
+pub const WABUN_ALPHABET: [(char, &str); 51] = [
+    ('あ', "朝日"),
+    ('い', "いろは"),
+    ('う', "上野"),
+    ('え', "英語"),
+    ('お', "大阪"),
+    ('か', "為替"),
+    ('き', "切手"),
+    ('く', "クラブ"),
+    ('け', "景色"),
+    ('こ', "子供"),
+    ('さ', "桜"),
+    ('し', "新聞"),
+    ('す', "すずめ"),
+    ('せ', "世界"),
+    ('そ', "そろばん"),
+    ('た', "煙草"),
+    ('ち', "ちどり"),
+    ('つ', "つるかめ"),
+    ('て', "手紙"),
+    ('と', "東京"),
+    ('な', "名古屋"),
+    ('に', "日本"),
+    ('ぬ', "沼津"),
+    ('ね', "ねずみ"),
+    ('の', "野原"),
+    ('は', "はがき"),
+    ('ひ', "飛行機"),
+    ('ふ', "富士山"),
+    ('へ', "平和"),
+    ('ほ', "保険"),
+    ('ま', "マッチ"),
+    ('み', "三笠"),
+    ('む', "無線"),
+    ('め', "明治"),
+    ('も', "もみじ"),
+    ('や', "大和"),
+    ('ゆ', "弓矢"),
+    ('よ', "吉野"),
+    ('ら', "ラジオ"),
+    ('り', "りんご"),
+    ('る', "留守居"),
+    ('れ', "れんげ"),
+    ('ろ', "ローマ"),
+    ('わ', "わらび"),
+    ('ゐ', "ゐど"),
+    ('を', "尾張"),
+    ('ん', "おしまい"),
+    ('ー', "長音"),
+    ('、', "区切点"),
+    ('\u{309b}', "濁点"),
+    ('\u{309c}', "半濁点"),
+];
+
+// Western Union
+pub const WESTERN_UNION_ALPHABET: [(char, &str); 26] = [
+    ('a', "Adams"),
+    ('b', "Boston"),
+    ('c', "Chicago"),
+    ('d', "Denver"),
+    ('e', "Easy"),
+    ('f', "Frank"),
+    ('g', "George"),
+    ('h', "Henry"),
+    ('i', "Ida"),
+    ('j', "John"),
+    ('k', "King"),
+    ('l', "Lincoln"),
+    ('m', "Mary"),
+    ('n', "New|York"),
+    ('o', "Ocean"),
+    ('p', "Peter"),
+    ('q', "Queen"),
+    ('r', "Roger"),
+    ('s', "Sugar"),
+    ('t', "Thomas"),
+    ('u', "Union"),
+    ('v', "Victor"),
+    ('w', "William"),
+    ('x', "X-ray"),
+    ('y', "Young"),
+    ('z', "Zero"),
+];
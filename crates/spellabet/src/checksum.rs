@@ -0,0 +1,211 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::PhoneticConverter;
+
+// The break between the spelled text and its checksum.
+const CHECKSUM_BREAK: &str = " / ";
+
+// The word that introduces a spelled checksum.
+const CHECKSUM_LABEL: &str = "Checksum";
+
+// The most words that a single code word is made of, like "Double Quote".
+const MAX_CODE_WORD_LEN: usize = 4;
+
+/// A short check value of text, read back as two digits to confirm that the
+/// text was copied correctly.
+///
+/// The check value follows ISO 7064 MOD 97-10, computed over the UTF-8 bytes of
+/// the text. Among printable ASCII characters, it catches any single mistyped
+/// character and any swap of two neighboring characters. Case is significant.
+///
+/// # Examples
+///
+/// ```
+/// # use spellabet::Checksum;
+/// assert_eq!(Checksum::of("HELLO").to_string(), "63");
+/// assert_ne!(Checksum::of("HELLO"), Checksum::of("HLELO"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Checksum(u8);
+
+impl Checksum {
+    /// Computes and returns the checksum of the given text.
+    #[must_use]
+    pub fn of(text: &str) -> Self {
+        let remainder = text.bytes().fold(0, |remainder, byte| {
+            (remainder * 256 + u32::from(byte)) % 97
+        });
+        // appending the check digits makes the whole remainder equal one
+        let check = 98 - (remainder * 100) % 97;
+        Self(u8::try_from(check).unwrap_or_default())
+    }
+
+    /// Returns the check value, from 2 to 98.
+    #[must_use]
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl fmt::Display for Checksum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+/// The reason that a spoken checksum didn't confirm the text.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ChecksumError {
+    /// A word of the spoken checksum isn't a digit or the code word of one.
+    UnknownWord { word: String },
+    /// The spoken checksum doesn't have two digits.
+    InvalidLength { length: usize },
+    /// The spoken checksum doesn't match the checksum of the text.
+    Mismatch {
+        expected: Checksum,
+        spoken: Checksum,
+    },
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownWord { word } => write!(f, "{word:?} isn't a digit or its code word"),
+            Self::InvalidLength { length } => {
+                write!(f, "expected 2 digits, but {length} were spoken")
+            }
+            Self::Mismatch { expected, spoken } => {
+                write!(f, "the checksum is {expected}, but {spoken} was spoken")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ChecksumError {}
+
+impl PhoneticConverter {
+    /// Converts the given text into a string of code words like
+    /// [`convert`](Self::convert), followed by its spelled [`Checksum`] for
+    /// the listener to read back.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to convert into code words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert_eq!(
+    ///     converter.convert_with_checksum("HI"),
+    ///     "HOTEL INDIA / Checksum Six Seven"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn convert_with_checksum(&self, text: &str) -> String {
+        let mut result = self.convert(text);
+        result.push_str(CHECKSUM_BREAK);
+        result.push_str(&self.convert_checksum(text));
+        result
+    }
+
+    /// Returns the [`Checksum`] of the given text, spelled with the digits of
+    /// the current spelling alphabet, like "Checksum Six Seven".
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to compute the checksum of.
+    #[must_use]
+    pub fn convert_checksum(&self, text: &str) -> String {
+        let digits = self.convert(&Checksum::of(text).to_string());
+        let mut result = String::from(CHECKSUM_LABEL);
+        result.push_str(if self.nonce_form { ", " } else { " " });
+        result.push_str(&digits);
+        result
+    }
+
+    /// Checks the spoken checksum against the checksum of the given text.
+    ///
+    /// The spoken checksum may be given as digits, like "67", or as code words
+    /// of the current spelling alphabet, like "Six Seven", matched ignoring
+    /// case. A leading "Checksum" is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text as it was copied.
+    /// * `spoken` - The checksum as it was read back.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the spoken checksum can't be
+    /// read, or if it doesn't match the checksum of the text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert!(converter.verify("HI", "Checksum Six Seven").is_ok());
+    /// assert!(converter.verify("HI", "67").is_ok());
+    /// assert!(converter.verify("HU", "six seven").is_err());
+    /// ```
+    pub fn verify(&self, text: &str, spoken: &str) -> Result<(), ChecksumError> {
+        let spoken = self.parse_checksum(spoken)?;
+        let expected = Checksum::of(text);
+        if spoken == expected {
+            Ok(())
+        } else {
+            Err(ChecksumError::Mismatch { expected, spoken })
+        }
+    }
+
+    // Read the digits of a spoken checksum, matching the longest code word
+    // that starts at each word.
+    fn parse_checksum(&self, spoken: &str) -> Result<Checksum, ChecksumError> {
+        let mut words: Vec<&str> = spoken
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words
+            .first()
+            .is_some_and(|word| word.eq_ignore_ascii_case(CHECKSUM_LABEL))
+        {
+            words.remove(0);
+        }
+
+        let mut digits = String::new();
+        let mut i = 0;
+        while i < words.len() {
+            if words[i].chars().all(|c| c.is_ascii_digit()) {
+                digits.push_str(words[i]);
+                i += 1;
+                continue;
+            }
+            let longest = (1..=MAX_CODE_WORD_LEN.min(words.len() - i))
+                .rev()
+                .find_map(|len| {
+                    let digit = self
+                        .character_for(&words[i..i + len].join(" "))
+                        .filter(char::is_ascii_digit)?;
+                    Some((digit, len))
+                });
+            let Some((digit, len)) = longest else {
+                return Err(ChecksumError::UnknownWord {
+                    word: words[i].to_string(),
+                });
+            };
+            digits.push(digit);
+            i += len;
+        }
+
+        match digits.parse() {
+            Ok(value) if digits.len() == 2 => Ok(Checksum(value)),
+            _ => Err(ChecksumError::InvalidLength {
+                length: digits.len(),
+            }),
+        }
+    }
+}
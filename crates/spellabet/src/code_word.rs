@@ -0,0 +1,273 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::code_words::PRONUNCIATIONS;
+
+/// A code word, along with its lowercase and uppercase forms.
+///
+/// Code words are written as a single string, like "`DoubleQuote`" or
+/// "`X-ray`", and keep the words within them, so that compound code words can
+/// be rendered in any [`WordStyle`]. The built-in code words store the words
+/// they're made of, and other code words are split apart at whitespace.
+///
+/// The code words of the built-in alphabets are static, with their lowercase
+/// and uppercase forms and their words computed when the crate is built, so
+/// that converting text doesn't allocate for each character.
+///
+/// # Examples
+///
+/// ```
+/// # use spellabet::{PhoneticConverter, SpellingAlphabet, WordStyle};
+/// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+/// let code_word = &converter.code_words()[&'"'];
+/// assert_eq!(code_word.as_str(), "DoubleQuote");
+/// assert_eq!(code_word.words(), ["Double", "Quote"]);
+/// assert_eq!(code_word.render(WordStyle::Kebab), "double-quote");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CodeWord {
+    /// The code word as written.
+    text: Cow<'static, str>,
+    /// The code word in lowercase.
+    lowercase: Cow<'static, str>,
+    /// The code word in uppercase.
+    uppercase: Cow<'static, str>,
+    /// The words within the code word.
+    words: Cow<'static, [Cow<'static, str>]>,
+}
+
+/// A style for rendering compound code words, like "`DoubleQuote`".
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum WordStyle {
+    /// Lowercase words joined by hyphens, like "double-quote".
+    Kebab,
+    /// Code words as written, like "`DoubleQuote`".
+    /// This is the default.
+    #[default]
+    Pascal,
+    /// Uppercase words joined by underscores, like "`DOUBLE_QUOTE`".
+    ScreamingSnake,
+    /// Lowercase words separated by spaces, like "double quote".
+    Spaced,
+}
+
+/// The standard pronunciation of a code word.
+///
+/// # Examples
+///
+/// ```
+/// # use spellabet::CodeWord;
+/// let pronunciation = CodeWord::new("Alfa").pronunciation().unwrap();
+/// assert_eq!(pronunciation.respelling(), "AL FAH");
+/// assert_eq!(pronunciation.ipa(), "ˈælfɑ");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Pronunciation {
+    /// The respelling, with syllables separated by spaces.
+    respelling: &'static str,
+    /// The International Phonetic Alphabet (IPA) transcription.
+    ipa: &'static str,
+}
+
+impl Pronunciation {
+    /// Returns the pronunciation respelled using English spelling, like "AL
+    /// FAH" for "Alfa".
+    #[must_use]
+    pub const fn respelling(&self) -> &'static str {
+        self.respelling
+    }
+
+    /// Returns the pronunciation in the International Phonetic Alphabet (IPA),
+    /// like "ˈælfɑ" for "Alfa".
+    #[must_use]
+    pub const fn ipa(&self) -> &'static str {
+        self.ipa
+    }
+}
+
+impl CodeWord {
+    /// Creates and returns a new `CodeWord` from the given text, which is
+    /// borrowed rather than copied when it's a `&'static str`. The words
+    /// within it are split apart at whitespace.
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'static, str>>) -> Self {
+        let text = text.into();
+        let lowercase = to_lowercase(&text);
+        let uppercase = to_uppercase(&text);
+        let words = match &text {
+            Cow::Borrowed(text) => text.split_whitespace().map(Cow::Borrowed).collect(),
+            Cow::Owned(text) => text
+                .split_whitespace()
+                .map(|word| Cow::Owned(String::from(word)))
+                .collect(),
+        };
+        Self {
+            text,
+            lowercase,
+            uppercase,
+            words: Cow::Owned(words),
+        }
+    }
+
+    /// Creates and returns a new `CodeWord` from the given words, written
+    /// joined together, like "`NewYork`".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{CodeWord, WordStyle};
+    /// let code_word = CodeWord::joined(["New", "York"]);
+    /// assert_eq!(code_word.as_str(), "NewYork");
+    /// assert_eq!(code_word.render(WordStyle::Spaced), "new york");
+    /// ```
+    #[must_use]
+    pub fn joined<S: Into<String>>(words: impl IntoIterator<Item = S>) -> Self {
+        let words: Vec<Cow<'static, str>> = words
+            .into_iter()
+            .map(|word| Cow::Owned(word.into()))
+            .collect();
+        Self {
+            words: Cow::Owned(words.clone()),
+            ..Self::new(words.concat())
+        }
+    }
+
+    // Creates a code word of the built-in tables, whose lowercase and uppercase
+    // forms and words are computed by the build script.
+    pub(crate) const fn from_static(
+        text: &'static str,
+        lowercase: &'static str,
+        uppercase: &'static str,
+        words: &'static [Cow<'static, str>],
+    ) -> Self {
+        Self {
+            text: Cow::Borrowed(text),
+            lowercase: Cow::Borrowed(lowercase),
+            uppercase: Cow::Borrowed(uppercase),
+            words: Cow::Borrowed(words),
+        }
+    }
+
+    /// Returns the code word as written.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the code word in lowercase, like "x-ray" for "X-ray".
+    #[must_use]
+    pub fn lowercase(&self) -> &str {
+        &self.lowercase
+    }
+
+    /// Returns the code word in uppercase, like "X-RAY" for "X-ray".
+    #[must_use]
+    pub fn uppercase(&self) -> &str {
+        &self.uppercase
+    }
+
+    /// Returns the words within the code word.
+    #[must_use]
+    pub fn words(&self) -> &[Cow<'static, str>] {
+        &self.words
+    }
+
+    // Returns the code word in the case of the given character, like
+    // `match_case()` but without allocating.
+    pub(crate) fn cased(&self, character: char) -> &str {
+        if character.is_lowercase() {
+            &self.lowercase
+        } else if character.is_uppercase() {
+            &self.uppercase
+        } else {
+            &self.text
+        }
+    }
+
+    /// Returns the standard pronunciation of the code word, if known.
+    ///
+    /// Pronunciations are known for the code words of the International Civil
+    /// Aviation Organization (ICAO), like those of the NATO alphabet, and
+    /// apply wherever the same code word is used.
+    #[must_use]
+    pub fn pronunciation(&self) -> Option<Pronunciation> {
+        pronunciation_of(&self.text)
+    }
+
+    /// Renders the code word in the given style.
+    #[must_use]
+    pub fn render(&self, style: WordStyle) -> Cow<'_, str> {
+        match style {
+            WordStyle::Kebab => Cow::Owned(self.words().join("-").to_lowercase()),
+            WordStyle::Pascal => Cow::Borrowed(&self.text),
+            WordStyle::ScreamingSnake => Cow::Owned(self.words().join("_").to_uppercase()),
+            WordStyle::Spaced => Cow::Owned(self.words().join(" ").to_lowercase()),
+        }
+    }
+}
+
+impl fmt::Display for CodeWord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl From<&str> for CodeWord {
+    fn from(text: &str) -> Self {
+        Self::new(String::from(text))
+    }
+}
+
+impl From<String> for CodeWord {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl PartialEq<str> for CodeWord {
+    fn eq(&self, other: &str) -> bool {
+        self.text == other
+    }
+}
+
+impl PartialEq<&str> for CodeWord {
+    fn eq(&self, other: &&str) -> bool {
+        self.text == *other
+    }
+}
+
+// Look up the standard pronunciation of a code word, ignoring case.
+pub fn pronunciation_of(code_word: &str) -> Option<Pronunciation> {
+    PRONUNCIATIONS
+        .iter()
+        .find(|(word, _, _)| word.eq_ignore_ascii_case(code_word))
+        .map(|(_, respelling, ipa)| Pronunciation { respelling, ipa })
+}
+
+// Convert text to lowercase, borrowing static text that's already lowercase.
+// ASCII text takes a fast path that skips the Unicode case tables.
+#[allow(clippy::ptr_arg)]
+fn to_lowercase(text: &Cow<'static, str>) -> Cow<'static, str> {
+    match text {
+        Cow::Borrowed(text) if text.chars().all(|c| c.to_lowercase().eq([c])) => {
+            Cow::Borrowed(text)
+        }
+        _ if text.is_ascii() => Cow::Owned(text.to_ascii_lowercase()),
+        _ => Cow::Owned(text.to_lowercase()),
+    }
+}
+
+// Convert text to uppercase, borrowing static text that's already uppercase.
+// ASCII text takes a fast path that skips the Unicode case tables.
+#[allow(clippy::ptr_arg)]
+fn to_uppercase(text: &Cow<'static, str>) -> Cow<'static, str> {
+    match text {
+        Cow::Borrowed(text) if text.chars().all(|c| c.to_uppercase().eq([c])) => {
+            Cow::Borrowed(text)
+        }
+        _ if text.is_ascii() => Cow::Owned(text.to_ascii_uppercase()),
+        _ => Cow::Owned(text.to_uppercase()),
+    }
+}
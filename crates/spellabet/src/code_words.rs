@@ -1,233 +1,41 @@
-pub const DEFAULT_DIGITS_AND_SYMBOLS: [(char, &str); 43] = [
-    ('0', "Zero"),
-    ('1', "One"),
-    ('2', "Two"),
-    ('3', "Three"),
-    ('4', "Four"),
-    ('5', "Five"),
-    ('6', "Six"),
-    ('7', "Seven"),
-    ('8', "Eight"),
-    ('9', "Nine"),
-    (' ', "Space"),
-    ('!', "Exclamation"),
-    ('"', "DoubleQuote"),
-    ('#', "Hash"),
-    ('$', "Dollars"),
-    ('%', "Percent"),
-    ('&', "Ampersand"),
-    ('(', "LeftParens"),
-    (')', "RightParens"),
-    ('*', "Asterisk"),
-    ('+', "Plus"),
-    (',', "Comma"),
-    ('-', "Dash"),
-    ('.', "Period"),
-    ('/', "ForeSlash"),
-    (':', "Colon"),
-    (';', "SemiColon"),
-    ('<', "LessThan"),
-    ('=', "Equals"),
-    ('>', "GreaterThan"),
-    ('?', "Question"),
-    ('@', "At"),
-    ('[', "LeftBracket"),
-    ('\'', "SingleQuote"),
-    ('\\', "BackSlash"),
-    (']', "RightBracket"),
-    ('^', "Caret"),
-    ('_', "Underscore"),
-    ('`', "Backtick"),
-    ('{', "LeftBrace"),
-    ('|', "Pipe"),
-    ('}', "RightBrace"),
-    ('~', "Tilde"),
-];
+// The code word tables of the built-in spelling alphabets and vocabularies,
+// generated by the build script from `data/code_words.rs`.
 
-// Joint Army/Navy (JAN)
-pub const JAN_ALPHABET: [(char, &str); 26] = [
-    ('a', "Able"),
-    ('b', "Baker"),
-    ('c', "Charlie"),
-    ('d', "Dog"),
-    ('e', "Easy"),
-    ('f', "Fox"),
-    ('g', "George"),
-    ('h', "How"),
-    ('i', "Item"),
-    ('j', "Jig"),
-    ('k', "King"),
-    ('l', "Love"),
-    ('m', "Mike"),
-    ('n', "Nan"),
-    ('o', "Oboe"),
-    ('p', "Peter"),
-    ('q', "Queen"),
-    ('r', "Roger"),
-    ('s', "Sugar"),
-    ('t', "Tare"),
-    ('u', "Uncle"),
-    ('v', "Victor"),
-    ('w', "William"),
-    ('x', "X-ray"),
-    ('y', "Yoke"),
-    ('z', "Zebra"),
-];
+use alloc::borrow::Cow;
 
-// Los Angeles Police Department (LAPD)
-// Association of Public-Safety Communications Officials-International (APCO)
-pub const LAPD_ALPHABET: [(char, &str); 27] = [
-    ('a', "Adam"),
-    ('b', "Boy"),
-    ('c', "Charles"),
-    ('d', "David"),
-    ('e', "Edward"),
-    ('f', "Frank"),
-    ('g', "George"),
-    ('h', "Henry"),
-    ('i', "Ida"),
-    ('j', "John"),
-    ('k', "King"),
-    ('l', "Lincoln"),
-    ('m', "Mary"),
-    ('n', "Nora"),
-    ('o', "Ocean"),
-    ('p', "Paul"),
-    ('q', "Queen"),
-    ('r', "Robert"),
-    ('s', "Sam"),
-    ('t', "Tom"),
-    ('u', "Union"),
-    ('v', "Victor"),
-    ('w', "William"),
-    ('x', "X-ray"),
-    ('y', "Young"),
-    ('z', "Zebra"),
-    ('9', "Niner"),
-];
+use crate::CodeWord;
 
-// North Atlantic Treaty Organization (NATO)
-// International Civil Aviation Organization (ICAO)
-// International Radiotelephony Spelling Alphabet (IRSA)
-pub const NATO_ALPHABET: [(char, &str); 30] = [
-    ('a', "Alfa"),
-    ('b', "Bravo"),
-    ('c', "Charlie"),
-    ('d', "Delta"),
-    ('e', "Echo"),
-    ('f', "Foxtrot"),
-    ('g', "Golf"),
-    ('h', "Hotel"),
-    ('i', "India"),
-    ('j', "Juliett"),
-    ('k', "Kilo"),
-    ('l', "Lima"),
-    ('m', "Mike"),
-    ('n', "November"),
-    ('o', "Oscar"),
-    ('p', "Papa"),
-    ('q', "Quebec"),
-    ('r', "Romeo"),
-    ('s', "Sierra"),
-    ('t', "Tango"),
-    ('u', "Uniform"),
-    ('v', "Victor"),
-    ('w', "Whiskey"),
-    ('x', "X-ray"),
-    ('y', "Yankee"),
-    ('z', "Zulu"),
-    ('3', "Tree"),
-    ('4', "Fower"),
-    ('5', "Fife"),
-    ('9', "Niner"),
-];
+// A static table of code words, sorted by character, along with an index of
+// its characters sorted by the `index_key()` of their code words.
+pub struct Table {
+    code_words: &'static [(char, CodeWord)],
+    index: &'static [(&'static str, char)],
+}
 
-// Royal Navy
-pub const ROYAL_NAVY_ALPHABET: [(char, &str); 26] = [
-    ('a', "Apples"),
-    ('b', "Butter"),
-    ('c', "Charlie"),
-    ('d', "Duff"),
-    ('e', "Edward"),
-    ('f', "Freddy"),
-    ('g', "George"),
-    ('h', "Harry"),
-    ('i', "Ink"),
-    ('j', "Johnnie"),
-    ('k', "King"),
-    ('l', "London"),
-    ('m', "Monkey"),
-    ('n', "Nuts"),
-    ('o', "Orange"),
-    ('p', "Pudding"),
-    ('q', "Queenie"),
-    ('r', "Robert"),
-    ('s', "Sugar"),
-    ('t', "Tommy"),
-    ('u', "Uncle"),
-    ('v', "Vinegar"),
-    ('w', "William"),
-    ('x', "Xerxes"),
-    ('y', "Yellow"),
-    ('z', "Zebra"),
-];
+impl Table {
+    // Returns the code word of the given character, if the table has one.
+    pub fn get(&self, character: char) -> Option<&'static CodeWord> {
+        self.code_words
+            .binary_search_by_key(&character, |(c, _)| *c)
+            .ok()
+            .map(|i| &self.code_words[i].1)
+    }
 
-// United States Financial Industry
-pub const US_FINANCIAL_ALPHABET: [(char, &str); 26] = [
-    ('a', "Adam"),
-    ('b', "Bob"),
-    ('c', "Carol"),
-    ('d', "David"),
-    ('e', "Eddie"),
-    ('f', "Frank"),
-    ('g', "George"),
-    ('h', "Harry"),
-    ('i', "Ike"),
-    ('j', "Jim"),
-    ('k', "Kenny"),
-    ('l', "Larry"),
-    ('m', "Mary"),
-    ('n', "Nancy"),
-    ('o', "Oliver"),
-    ('p', "Peter"),
-    ('q', "Quincy"),
-    ('r', "Roger"),
-    ('s', "Sam"),
-    ('t', "Thomas"),
-    ('u', "Uncle"),
-    ('v', "Vincent"),
-    ('w', "William"),
-    ('x', "Xavier"),
-    ('y', "Yogi"),
-    ('z', "Zachary"),
-];
+    // Iterates over the characters and code words of the table.
+    pub fn iter(&self) -> impl Iterator<Item = (char, &'static CodeWord)> {
+        self.code_words
+            .iter()
+            .map(|(character, code_word)| (*character, code_word))
+    }
 
-// Western Union
-pub const WESTERN_UNION_ALPHABET: [(char, &str); 26] = [
-    ('a', "Adams"),
-    ('b', "Boston"),
-    ('c', "Chicago"),
-    ('d', "Denver"),
-    ('e', "Easy"),
-    ('f', "Frank"),
-    ('g', "George"),
-    ('h', "Henry"),
-    ('i', "Ida"),
-    ('j', "John"),
-    ('k', "King"),
-    ('l', "Lincoln"),
-    ('m', "Mary"),
-    ('n', "NewYork"),
-    ('o', "Ocean"),
-    ('p', "Peter"),
-    ('q', "Queen"),
-    ('r', "Roger"),
-    ('s', "Sugar"),
-    ('t', "Thomas"),
-    ('u', "Union"),
-    ('v', "Victor"),
-    ('w', "William"),
-    ('x', "X-ray"),
-    ('y', "Young"),
-    ('z', "Zero"),
-];
+    // Iterates over the characters whose code word has the given index key.
+    pub fn characters_for(&self, key: &str) -> impl Iterator<Item = char> {
+        let start = self.index.partition_point(|(k, _)| *k < key);
+        self.index[start..]
+            .iter()
+            .take_while(move |(k, _)| *k == key)
+            .map(|(_, character)| *character)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/code_words.rs"));
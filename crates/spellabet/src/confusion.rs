@@ -0,0 +1,263 @@
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{CodeWord, PhoneticConverter, custom_char_ordering};
+
+// Groups of sounds that are easily mistaken for one another over a noisy
+// channel, like the rhyming "B", "D", and "V". The sounds are those produced
+// by `phonetic_key()`.
+const SOUND_GROUPS: [&str; 5] = ["bdgkptvz", "fhjsxθʃ", "lr", "mn", "wy"];
+
+// The cost of substituting one consonant for another from the same group.
+// Consonants are the first sounds to be lost over a noisy channel, so they
+// cost less to confuse than vowels.
+const CONSONANT_COST: f64 = 0.25;
+
+// The cost of substituting one vowel for another.
+const VOWEL_COST: f64 = 0.5;
+
+/// A pair of code words that may be mistaken for one another when spoken.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+///
+/// let mut overrides_map = HashMap::new();
+/// overrides_map.insert('b', "Bee".to_string());
+/// overrides_map.insert('d', "Dee".to_string());
+///
+/// let converter =
+///     PhoneticConverter::new(&SpellingAlphabet::default()).with_overrides(overrides_map);
+/// let pairs = converter.confusable_pairs(0.85);
+/// assert_eq!(pairs[0].characters(), ('b', 'd'));
+/// assert!(pairs[0].is_overridden());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfusablePair {
+    /// The characters spelled by the code words.
+    characters: (char, char),
+    /// The code words, in the order of their characters.
+    code_words: (CodeWord, CodeWord),
+    /// How alike the code words sound.
+    similarity: f64,
+    /// Is set when either code word comes from an override.
+    overridden: bool,
+}
+
+impl ConfusablePair {
+    /// Returns the characters spelled by the code words.
+    #[must_use]
+    pub const fn characters(&self) -> (char, char) {
+        self.characters
+    }
+
+    /// Returns the code words, in the order of their characters.
+    #[must_use]
+    pub const fn code_words(&self) -> (&CodeWord, &CodeWord) {
+        (&self.code_words.0, &self.code_words.1)
+    }
+
+    /// Returns how alike the code words sound, from 0.0 for nothing in common
+    /// to 1.0 for sounding the same. See [`CodeWord::similarity`].
+    #[must_use]
+    pub const fn similarity(&self) -> f64 {
+        self.similarity
+    }
+
+    /// Returns true if either code word comes from an override.
+    #[must_use]
+    pub const fn is_overridden(&self) -> bool {
+        self.overridden
+    }
+}
+
+impl CodeWord {
+    /// Returns how alike this code word sounds to another, from 0.0 for
+    /// nothing in common to 1.0 for sounding the same.
+    ///
+    /// Each code word is reduced to a phonetic key of its sounds, taken from
+    /// its respelling when its pronunciation is known, or else from its
+    /// English spelling. The keys are then compared by edit distance, with
+    /// vowels and easily confused consonants like "B" and "D" counting as only
+    /// part of a difference. This is an approximation meant to flag risky
+    /// pairs, not a measure of how a listener will hear them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::CodeWord;
+    /// let bee = CodeWord::new("Bee");
+    /// assert!(bee.similarity(&CodeWord::new("Dee")) > 0.85);
+    /// assert!(bee.similarity(&CodeWord::new("Bravo")) < 0.5);
+    /// ```
+    #[must_use]
+    pub fn similarity(&self, other: &Self) -> f64 {
+        key_similarity(&self.phonetic_key(), &other.phonetic_key())
+    }
+
+    fn phonetic_key(&self) -> Vec<char> {
+        let text = self
+            .pronunciation()
+            .map_or_else(|| self.words().join(" "), |p| p.respelling().to_string());
+        phonetic_key(&text)
+    }
+}
+
+impl PhoneticConverter {
+    /// Returns the pairs of code words in the current character mappings of
+    /// the `PhoneticConverter` instance that sound at least as alike as the
+    /// given threshold, with the riskiest pairs first.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The lowest [`CodeWord::similarity`] to report, from 0.0
+    ///   to 1.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// for pair in converter.confusable_pairs(0.5).iter().take(3) {
+    ///     let (a, b) = pair.characters();
+    ///     println!("{a} and {b}: {:.2}", pair.similarity());
+    /// }
+    /// ```
+    #[must_use]
+    pub fn confusable_pairs(&self, threshold: f64) -> Vec<ConfusablePair> {
+        let entries: Vec<_> = self
+            .sorted_mappings()
+            .into_iter()
+            .map(|(character, code_word)| {
+                let key = code_word.phonetic_key();
+                (character, code_word, key)
+            })
+            .collect();
+
+        let mut pairs = Vec::new();
+        for (i, (first, first_word, first_key)) in entries.iter().enumerate() {
+            for (second, second_word, second_key) in &entries[i + 1..] {
+                let similarity = key_similarity(first_key, second_key);
+                if similarity >= threshold {
+                    pairs.push(ConfusablePair {
+                        characters: (*first, *second),
+                        code_words: (first_word.clone(), second_word.clone()),
+                        similarity,
+                        overridden: self.overrides.contains_key(first)
+                            || self.overrides.contains_key(second),
+                    });
+                }
+            }
+        }
+
+        pairs.sort_by(|a, b| {
+            b.similarity
+                .total_cmp(&a.similarity)
+                .then_with(|| custom_char_ordering(a.characters.0, b.characters.0))
+                .then_with(|| custom_char_ordering(a.characters.1, b.characters.1))
+        });
+        pairs
+    }
+}
+
+// Reduce text to a rough key of its sounds, following common English spelling
+// rules. Each word is reduced separately, and letters of other scripts are
+// kept as they are.
+fn phonetic_key(text: &str) -> Vec<char> {
+    let mut key = Vec::new();
+    for word in text.split_whitespace() {
+        let mut letters: Vec<char> = word
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .flat_map(char::to_lowercase)
+            .collect();
+        // drop a silent final "e", like in "Mike"
+        if letters.len() > 2 && letters.ends_with(&['e']) && !is_vowel(letters[letters.len() - 2]) {
+            letters.pop();
+        }
+
+        let mut i = 0;
+        while i < letters.len() {
+            let next = letters.get(i + 1).copied();
+            let (sounds, len) = match (letters[i], next) {
+                ('p', Some('h')) => ("f", 2),
+                ('c', Some('k')) => ("k", 2),
+                ('c' | 's', Some('h')) => ("ʃ", 2),
+                ('t', Some('h')) => ("θ", 2),
+                ('w', Some('h')) => ("w", 2),
+                ('q', Some('u')) => ("kw", 2),
+                ('e', Some('e' | 'a')) | ('i', Some('e')) => ("i", 2),
+                ('o', Some('o' | 'u')) => ("u", 2),
+                ('a' | 'e', Some('i' | 'y')) => ("e", 2),
+                ('c', Some('e' | 'i' | 'y')) => ("s", 1),
+                ('c' | 'q', _) => ("k", 1),
+                ('x', _) => ("ks", 1),
+                ('y', _) if i == 0 => ("y", 1),
+                ('y', _) => ("i", 1),
+                ('h', _) if i > 0 => ("", 1),
+                (letter, _) => {
+                    push_sound(&mut key, letter);
+                    i += 1;
+                    continue;
+                }
+            };
+            for sound in sounds.chars() {
+                push_sound(&mut key, sound);
+            }
+            i += len;
+        }
+    }
+    key
+}
+
+// Push a sound onto the key, collapsing doubled sounds like in "Juliett".
+fn push_sound(key: &mut Vec<char>, sound: char) {
+    if key.last() != Some(&sound) {
+        key.push(sound);
+    }
+}
+
+const fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+// Compare phonetic keys by their weighted edit distance, scaled by the length
+// of the longer key.
+#[allow(clippy::cast_precision_loss)]
+fn key_similarity(a: &[char], b: &[char]) -> f64 {
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+
+    let mut previous: Vec<f64> = (0..=b.len()).map(|j| j as f64).collect();
+    for (i, &x) in a.iter().enumerate() {
+        let mut current = vec![(i + 1) as f64];
+        for (j, &y) in b.iter().enumerate() {
+            let substitution = previous[j] + substitution_cost(x, y);
+            let insertion = current[j] + 1.0;
+            let deletion = previous[j + 1] + 1.0;
+            current.push(substitution.min(insertion).min(deletion));
+        }
+        previous = current;
+    }
+    1.0 - previous[b.len()] / longest as f64
+}
+
+fn substitution_cost(x: char, y: char) -> f64 {
+    if x == y {
+        0.0
+    } else if is_vowel(x) && is_vowel(y) {
+        VOWEL_COST
+    } else if SOUND_GROUPS
+        .iter()
+        .any(|group| group.contains(x) && group.contains(y))
+    {
+        CONSONANT_COST
+    } else {
+        1.0
+    }
+}
@@ -0,0 +1,385 @@
+// Greek accents as spacing characters, so that they can be looked up in the
+// code word tables like any other key.
+const TONOS: Mark = Mark::Key('\u{0384}');
+const DIALYTIKA: Mark = Mark::Key('\u{00a8}');
+
+// Combining accents with the spacing marks they stand for, as found in text
+// that's in Normalization Form D.
+const COMBINING_GREEK_ACCENTS: [(char, &[Mark]); 4] = [
+    ('\u{0301}', &[TONOS]),
+    ('\u{0308}', &[DIALYTIKA]),
+    ('\u{0341}', &[TONOS]),
+    ('\u{0344}', &[DIALYTIKA, TONOS]),
+];
+
+// Kana voicing marks as spacing characters, and the modifier for small kana,
+// which has no character of its own.
+const DAKUTEN: Mark = Mark::Key('\u{309b}');
+const HANDAKUTEN: Mark = Mark::Key('\u{309c}');
+const SMALL: Mark = Mark::Word("小文字");
+
+// The offset between a katakana character and the matching hiragana.
+const KATAKANA_OFFSET: u32 = 0x60;
+
+// Arabic presentation forms, as runs of isolated, final, initial, and medial
+// forms that share a base letter. Lam-alef ligatures are decomposed instead.
+const ARABIC_PRESENTATION_FORMS: [(char, u32, char); 36] = [
+    ('\u{fe80}', 1, 'ء'),
+    ('\u{fe81}', 2, 'آ'),
+    ('\u{fe83}', 2, 'أ'),
+    ('\u{fe85}', 2, 'ؤ'),
+    ('\u{fe87}', 2, 'إ'),
+    ('\u{fe89}', 4, 'ئ'),
+    ('\u{fe8d}', 2, 'ا'),
+    ('\u{fe8f}', 4, 'ب'),
+    ('\u{fe93}', 2, 'ة'),
+    ('\u{fe95}', 4, 'ت'),
+    ('\u{fe99}', 4, 'ث'),
+    ('\u{fe9d}', 4, 'ج'),
+    ('\u{fea1}', 4, 'ح'),
+    ('\u{fea5}', 4, 'خ'),
+    ('\u{fea9}', 2, 'د'),
+    ('\u{feab}', 2, 'ذ'),
+    ('\u{fead}', 2, 'ر'),
+    ('\u{feaf}', 2, 'ز'),
+    ('\u{feb1}', 4, 'س'),
+    ('\u{feb5}', 4, 'ش'),
+    ('\u{feb9}', 4, 'ص'),
+    ('\u{febd}', 4, 'ض'),
+    ('\u{fec1}', 4, 'ط'),
+    ('\u{fec5}', 4, 'ظ'),
+    ('\u{fec9}', 4, 'ع'),
+    ('\u{fecd}', 4, 'غ'),
+    ('\u{fed1}', 4, 'ف'),
+    ('\u{fed5}', 4, 'ق'),
+    ('\u{fed9}', 4, 'ك'),
+    ('\u{fedd}', 4, 'ل'),
+    ('\u{fee1}', 4, 'م'),
+    ('\u{fee5}', 4, 'ن'),
+    ('\u{fee9}', 4, 'ه'),
+    ('\u{feed}', 2, 'و'),
+    ('\u{feef}', 2, 'ى'),
+    ('\u{fef1}', 4, 'ي'),
+];
+
+// Precomposed Hangul syllables are laid out algorithmically by their initial,
+// medial, and final jamo; see the Unicode Standard, section 3.12.
+const HANGUL_SYLLABLES: core::ops::RangeInclusive<u32> = 0xac00..=0xd7a3;
+const HANGUL_MEDIAL_COUNT: u32 = 21;
+const HANGUL_FINAL_COUNT: u32 = 28;
+
+// Initial, medial, and final jamo in syllable order, as compatibility jamo.
+const HANGUL_INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+const HANGUL_MEDIALS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+const HANGUL_FINALS: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// A mark, or a second letter of a ligature, that follows the base letter of a
+/// decomposed character.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Mark {
+    /// A mark spelled using the code word of this character, if any.
+    Key(char),
+    /// A mark with a fixed code word.
+    Word(&'static str),
+}
+
+impl Mark {
+    /// The particle that joins the mark to its letter in Japanese nonce form,
+    /// like "に" in "為替のカに濁点" ("KA of KAWASE, with dakuten").
+    pub const fn particle(self) -> &'static str {
+        match self {
+            Self::Key(_) => "に",
+            Self::Word(_) => "の",
+        }
+    }
+}
+
+/// A precomposed character split into its base letter and the marks that
+/// follow it.
+#[derive(Clone, Copy, Debug)]
+pub struct Decomposition {
+    pub base: char,
+    pub marks: &'static [Mark],
+    /// Accents are only spelled on request. Other marks, like the kana
+    /// dakuten, change the sound of the base letter and are always spelled.
+    pub is_accent: bool,
+}
+
+// Precomposed lowercase Greek vowels with their base letters and accents.
+const GREEK_ACCENTED: [(char, char, &[Mark]); 11] = [
+    ('ά', 'α', &[TONOS]),
+    ('έ', 'ε', &[TONOS]),
+    ('ή', 'η', &[TONOS]),
+    ('ί', 'ι', &[TONOS]),
+    ('ό', 'ο', &[TONOS]),
+    ('ύ', 'υ', &[TONOS]),
+    ('ώ', 'ω', &[TONOS]),
+    ('ϊ', 'ι', &[DIALYTIKA]),
+    ('ϋ', 'υ', &[DIALYTIKA]),
+    ('ΐ', 'ι', &[DIALYTIKA, TONOS]),
+    ('ΰ', 'υ', &[DIALYTIKA, TONOS]),
+];
+
+// Voiced, semi-voiced, and small hiragana with their base kana and modifiers.
+// Katakana is folded into hiragana before decomposition.
+const KANA_MODIFIED: [(char, char, &[Mark]); 38] = [
+    ('が', 'か', &[DAKUTEN]),
+    ('ぎ', 'き', &[DAKUTEN]),
+    ('ぐ', 'く', &[DAKUTEN]),
+    ('げ', 'け', &[DAKUTEN]),
+    ('ご', 'こ', &[DAKUTEN]),
+    ('ざ', 'さ', &[DAKUTEN]),
+    ('じ', 'し', &[DAKUTEN]),
+    ('ず', 'す', &[DAKUTEN]),
+    ('ぜ', 'せ', &[DAKUTEN]),
+    ('ぞ', 'そ', &[DAKUTEN]),
+    ('だ', 'た', &[DAKUTEN]),
+    ('ぢ', 'ち', &[DAKUTEN]),
+    ('づ', 'つ', &[DAKUTEN]),
+    ('で', 'て', &[DAKUTEN]),
+    ('ど', 'と', &[DAKUTEN]),
+    ('ば', 'は', &[DAKUTEN]),
+    ('び', 'ひ', &[DAKUTEN]),
+    ('ぶ', 'ふ', &[DAKUTEN]),
+    ('べ', 'へ', &[DAKUTEN]),
+    ('ぼ', 'ほ', &[DAKUTEN]),
+    ('ゔ', 'う', &[DAKUTEN]),
+    ('ぱ', 'は', &[HANDAKUTEN]),
+    ('ぴ', 'ひ', &[HANDAKUTEN]),
+    ('ぷ', 'ふ', &[HANDAKUTEN]),
+    ('ぺ', 'へ', &[HANDAKUTEN]),
+    ('ぽ', 'ほ', &[HANDAKUTEN]),
+    ('ぁ', 'あ', &[SMALL]),
+    ('ぃ', 'い', &[SMALL]),
+    ('ぅ', 'う', &[SMALL]),
+    ('ぇ', 'え', &[SMALL]),
+    ('ぉ', 'お', &[SMALL]),
+    ('っ', 'つ', &[SMALL]),
+    ('ゃ', 'や', &[SMALL]),
+    ('ゅ', 'ゆ', &[SMALL]),
+    ('ょ', 'よ', &[SMALL]),
+    ('ゎ', 'わ', &[SMALL]),
+    ('ゕ', 'か', &[SMALL]),
+    ('ゖ', 'け', &[SMALL]),
+];
+
+// Arabic lam-alef ligatures, in isolated and final form, spelled as lam
+// followed by the alef they contain.
+const LAM_ALEF_LIGATURES: [(char, char, &[Mark]); 8] = [
+    ('\u{fef5}', 'ل', &[Mark::Key('آ')]),
+    ('\u{fef6}', 'ل', &[Mark::Key('آ')]),
+    ('\u{fef7}', 'ل', &[Mark::Key('أ')]),
+    ('\u{fef8}', 'ل', &[Mark::Key('أ')]),
+    ('\u{fef9}', 'ل', &[Mark::Key('إ')]),
+    ('\u{fefa}', 'ل', &[Mark::Key('إ')]),
+    ('\u{fefb}', 'ل', &[Mark::Key('ا')]),
+    ('\u{fefc}', 'ل', &[Mark::Key('ا')]),
+];
+
+// Fold variants of a letter into the base letter that holds its code word,
+// like the Greek final sigma into sigma, katakana into hiragana, Hebrew final
+// forms into their base letters, or Arabic positional forms into theirs.
+pub const fn fold_variant(character: char) -> char {
+    match character {
+        'ς' => 'σ',
+        'ァ'..='ヶ' => match char::from_u32(character as u32 - KATAKANA_OFFSET) {
+            Some(hiragana) => hiragana,
+            None => character,
+        },
+        'ך' => 'כ',
+        'ם' => 'מ',
+        'ן' => 'נ',
+        'ף' => 'פ',
+        'ץ' => 'צ',
+        '\u{fe80}'..='\u{fef4}' => fold_arabic_presentation_form(character),
+        _ => character,
+    }
+}
+
+const fn fold_arabic_presentation_form(character: char) -> char {
+    let mut i = 0;
+    while i < ARABIC_PRESENTATION_FORMS.len() {
+        let (first, count, base) = ARABIC_PRESENTATION_FORMS[i];
+        if character as u32 >= first as u32 && (character as u32) < first as u32 + count {
+            return base;
+        }
+        i += 1;
+    }
+    character
+}
+
+// Write a base kana in the script of the character it was decomposed from,
+// since katakana is folded into hiragana before decomposition.
+pub const fn in_script_of(base: char, character: char) -> char {
+    match (base, character) {
+        ('ぁ'..='ゖ', 'ァ'..='ヶ') => match char::from_u32(base as u32 + KATAKANA_OFFSET) {
+            Some(katakana) => katakana,
+            None => base,
+        },
+        _ => base,
+    }
+}
+
+// Check for the invisible formatting characters that control bidirectional
+// text layout. Passing these through unconverted would garble the direction of
+// the surrounding output, so they're either named or dropped.
+pub const fn is_bidi_control(character: char) -> bool {
+    matches!(
+        character,
+        '\u{061c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+// Check for the Hebrew points and Arabic harakat that are written over or
+// under a letter to mark its vowel. These belong to the letter they follow,
+// which is spelled without them, like in the spelling alphabets themselves.
+pub const fn is_vowel_point(character: char) -> bool {
+    matches!(
+        character,
+        '\u{0591}'..='\u{05bd}'
+            | '\u{05bf}'
+            | '\u{05c1}'
+            | '\u{05c2}'
+            | '\u{05c4}'
+            | '\u{05c5}'
+            | '\u{05c7}'
+            | '\u{0610}'..='\u{061a}'
+            | '\u{064b}'..='\u{065f}'
+            | '\u{0670}'
+    )
+}
+
+// The characters of text as they're spelled: without the bidirectional
+// controls and vowel points that are dropped, unless they have a code word, and
+// with combining accents composed with their base letters. Each character is
+// held back until the next one shows whether it composes with it.
+pub struct VisibleChars<I, F> {
+    chars: I,
+    has_code_word: F,
+    pending: Option<char>,
+}
+
+impl<I: Iterator<Item = char>, F: Fn(char) -> bool> VisibleChars<I, F> {
+    pub const fn new(chars: I, has_code_word: F) -> Self {
+        Self {
+            chars,
+            has_code_word,
+            pending: None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>, F: Fn(char) -> bool> Iterator for VisibleChars<I, F> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        for c in self.chars.by_ref() {
+            let is_folded = is_vowel_point(c) && self.pending.is_some_and(char::is_alphabetic);
+            if (is_bidi_control(c) || is_folded) && !(self.has_code_word)(c) {
+                continue;
+            }
+            if let Some(last) = self.pending
+                && let Some(composed) = compose(last, c)
+            {
+                self.pending = Some(composed);
+                continue;
+            }
+            if let Some(last) = self.pending.replace(c) {
+                return Some(last);
+            }
+        }
+        self.pending.take()
+    }
+}
+
+// Split a precomposed, already normalized character into its base letter and
+// the marks that follow it. Returns `None` for characters without a known
+// decomposition.
+pub fn decompose(character: char) -> Option<Decomposition> {
+    let find = |table: &[(char, char, &'static [Mark])]| {
+        table
+            .iter()
+            .find(|(precomposed, _, _)| *precomposed == character)
+            .map(|(_, base, marks)| (*base, *marks))
+    };
+
+    if let Some((base, marks)) = find(&GREEK_ACCENTED) {
+        return Some(Decomposition {
+            base,
+            marks,
+            is_accent: true,
+        });
+    }
+
+    find(&KANA_MODIFIED)
+        .or_else(|| find(&LAM_ALEF_LIGATURES))
+        .map(|(base, marks)| Decomposition {
+            base,
+            marks,
+            is_accent: false,
+        })
+}
+
+// Combine a character with the combining accent that follows it into the
+// matching precomposed Greek vowel, so that decomposed text is spelled like
+// precomposed text. Returns `None` if there's no such vowel.
+pub fn compose(character: char, accent: char) -> Option<char> {
+    let (_, accent_marks) = COMBINING_GREEK_ACCENTS
+        .iter()
+        .find(|(combining, _)| *combining == accent)?;
+
+    let lower = single_char(character.to_lowercase())?;
+    let (base, marks) = GREEK_ACCENTED
+        .iter()
+        .find(|(precomposed, _, _)| *precomposed == lower)
+        .map_or((lower, [].as_slice()), |(_, base, marks)| (*base, *marks));
+    let (composed, _, _) = GREEK_ACCENTED.iter().find(|(_, b, m)| {
+        *b == base
+            && m.len() == marks.len() + accent_marks.len()
+            && m.starts_with(marks)
+            && m.ends_with(accent_marks)
+    })?;
+
+    if character.is_uppercase() {
+        single_char(composed.to_uppercase())
+    } else {
+        Some(*composed)
+    }
+}
+
+// The only character of a case mapping, if it maps to a single character.
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    let (Some(c), None) = (chars.next(), chars.next()) else {
+        return None;
+    };
+    Some(c)
+}
+
+// Split a precomposed Hangul syllable into its initial, medial, and optional
+// final jamo. Returns `None` for characters outside the syllables block.
+pub fn decompose_syllable(character: char) -> Option<(char, char, Option<char>)> {
+    let code_point = character as u32;
+    if !HANGUL_SYLLABLES.contains(&code_point) {
+        return None;
+    }
+
+    let index = code_point - HANGUL_SYLLABLES.start();
+    let initial = index / (HANGUL_MEDIAL_COUNT * HANGUL_FINAL_COUNT);
+    let medial = (index / HANGUL_FINAL_COUNT) % HANGUL_MEDIAL_COUNT;
+    let last = index % HANGUL_FINAL_COUNT;
+
+    Some((
+        HANGUL_INITIALS[initial as usize],
+        HANGUL_MEDIALS[medial as usize],
+        last.checked_sub(1).map(|i| HANGUL_FINALS[i as usize]),
+    ))
+}
@@ -0,0 +1,142 @@
+use alloc::vec::Vec;
+
+use crate::{CodeWord, PhoneticConverter, custom_char_ordering};
+
+/// A group of characters, in the order used by
+/// [`PhoneticConverter::sorted_mappings`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CharacterClass {
+    /// Alphabetic characters of any script.
+    Letter,
+    /// Numeric characters.
+    Digit,
+    /// All other characters, including whitespace.
+    Symbol,
+    /// Characters without a code word, as returned by
+    /// [`PhoneticConverter::classify`].
+    Unknown,
+}
+
+impl CharacterClass {
+    /// Returns the group of the given character, which is never
+    /// [`Unknown`](Self::Unknown).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::CharacterClass;
+    /// assert_eq!(CharacterClass::of('ж'), CharacterClass::Letter);
+    /// assert_eq!(CharacterClass::of('7'), CharacterClass::Digit);
+    /// assert_eq!(CharacterClass::of(' '), CharacterClass::Symbol);
+    /// ```
+    #[must_use]
+    pub fn of(character: char) -> Self {
+        if character.is_alphabetic() {
+            Self::Letter
+        } else if character.is_numeric() {
+            Self::Digit
+        } else {
+            Self::Symbol
+        }
+    }
+}
+
+/// A difference in the code word of one character between two
+/// `PhoneticConverter` instances.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Change {
+    /// The character only has a code word in the second converter.
+    Added {
+        character: char,
+        code_word: CodeWord,
+    },
+    /// The character has a different code word in each converter.
+    Changed {
+        character: char,
+        old: CodeWord,
+        new: CodeWord,
+    },
+    /// The character only has a code word in the first converter.
+    Removed {
+        character: char,
+        code_word: CodeWord,
+    },
+}
+
+impl Change {
+    /// Returns the character whose code word differs.
+    #[must_use]
+    pub const fn character(&self) -> char {
+        match self {
+            Self::Added { character, .. }
+            | Self::Changed { character, .. }
+            | Self::Removed { character, .. } => *character,
+        }
+    }
+
+    /// Returns the group of the character whose code word differs.
+    #[must_use]
+    pub fn class(&self) -> CharacterClass {
+        CharacterClass::of(self.character())
+    }
+}
+
+impl PhoneticConverter {
+    /// Compares the current character mappings of the `PhoneticConverter`
+    /// instance to those of another, including any overrides.
+    ///
+    /// Changes are returned in the order of their characters, sorted by
+    /// letters, then digits, then symbols. Code words are compared as written,
+    /// so a change in capitalization is a change.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The converter to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{Change, PhoneticConverter, SpellingAlphabet};
+    /// let nato = PhoneticConverter::new(&SpellingAlphabet::Nato);
+    /// let lapd = PhoneticConverter::new(&SpellingAlphabet::Lapd);
+    ///
+    /// let changes = nato.diff(&lapd);
+    /// assert_eq!(
+    ///     changes[0],
+    ///     Change::Changed {
+    ///         character: 'a',
+    ///         old: "Alfa".into(),
+    ///         new: "Adam".into(),
+    ///     }
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<Change> {
+        let mut changes = Vec::new();
+        for (character, old) in self.entries() {
+            match other.lookup(character) {
+                None => changes.push(Change::Removed {
+                    character,
+                    code_word: old.clone(),
+                }),
+                Some(new) if new.as_str() != old.as_str() => changes.push(Change::Changed {
+                    character,
+                    old: old.clone(),
+                    new: new.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        for (character, new) in other.entries() {
+            if self.lookup(character).is_none() {
+                changes.push(Change::Added {
+                    character,
+                    code_word: new.clone(),
+                });
+            }
+        }
+
+        changes.sort_by(|a, b| custom_char_ordering(a.character(), b.character()));
+        changes
+    }
+}
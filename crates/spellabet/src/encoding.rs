@@ -0,0 +1,249 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::PhoneticConverter;
+
+// The break between the groups of a UUID, in place of their hyphens.
+const GROUP_BREAK: &str = " / ";
+
+// The positions of the hyphens in a UUID, like
+// "123e4567-e89b-12d3-a456-426614174000".
+const UUID_HYPHENS: [usize; 4] = [8, 13, 18, 23];
+
+/// A machine encoding of text, like a hash or a token, which decides how its
+/// characters are spelled.
+///
+/// # Examples
+///
+/// ```
+/// # use spellabet::{Encoding, PhoneticConverter, SpellingAlphabet};
+/// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+///
+/// assert_eq!(converter.convert_encoded("Fa7", Encoding::Hex), "foxtrot alfa Seven");
+/// assert_eq!(
+///     converter.convert_encoded("Fa7", Encoding::Base64),
+///     "Uppercase FOXTROT Lowercase alfa Seven"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Encoding {
+    /// Base32, per RFC 4648, like a TOTP secret. Letters are case-insensitive,
+    /// so they're spelled in lowercase.
+    Base32,
+    /// Base64, per RFC 4648, in either the standard or the URL-safe alphabet.
+    /// Case is significant, so each letter is preceded by "Uppercase" or
+    /// "Lowercase".
+    Base64,
+    /// Hexadecimal digits, like a hash. Letters are case-insensitive, so
+    /// they're spelled in lowercase.
+    Hex,
+    /// A UUID, like "123e4567-e89b-12d3-a456-426614174000". Letters are
+    /// case-insensitive, and the hyphens between groups become group breaks.
+    Uuid,
+}
+
+/// The reason that text isn't valid in an [`Encoding`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum EncodingError {
+    /// The text is empty.
+    Empty,
+    /// A character isn't part of the encoding, at the given character index.
+    InvalidCharacter { character: char, index: usize },
+    /// The text has a number of characters that the encoding can't produce.
+    InvalidLength { length: usize },
+    /// Padding is misplaced, or there's too much of it, at the given
+    /// character index.
+    InvalidPadding { index: usize },
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "the text is empty"),
+            Self::InvalidCharacter { character, index } => {
+                write!(
+                    f,
+                    "invalid character {character:?} at position {}",
+                    index + 1
+                )
+            }
+            Self::InvalidLength { length } => write!(f, "invalid length of {length} characters"),
+            Self::InvalidPadding { index } => {
+                write!(f, "invalid padding at position {}", index + 1)
+            }
+        }
+    }
+}
+
+impl core::error::Error for EncodingError {}
+
+impl Encoding {
+    /// Checks that the given text is valid in the encoding.
+    ///
+    /// Hexadecimal text may have any number of digits. Base32 and Base64 text
+    /// may leave out its padding, but padding that's given must complete the
+    /// final block. Base64 text may use either the standard or the URL-safe
+    /// alphabet, but not both.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error describing the first problem found
+    /// in the text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{Encoding, EncodingError};
+    /// assert!(Encoding::Base32.validate("JBSWY3DPEE======").is_ok());
+    /// assert_eq!(
+    ///     Encoding::Base32.validate("JBSWY0DP"),
+    ///     Err(EncodingError::InvalidCharacter { character: '0', index: 5 })
+    /// );
+    /// ```
+    pub fn validate(&self, text: &str) -> Result<(), EncodingError> {
+        if text.is_empty() {
+            return Err(EncodingError::Empty);
+        }
+        match self {
+            Self::Base32 => validate_padded(
+                text,
+                8,
+                &[2, 4, 5, 7],
+                |c| matches!(c.to_ascii_uppercase(), 'A'..='Z' | '2'..='7'),
+            ),
+            Self::Base64 => {
+                let url_safe = text.contains(['-', '_']);
+                validate_padded(text, 4, &[2, 3], |c| match c {
+                    'A'..='Z' | 'a'..='z' | '0'..='9' => true,
+                    '+' | '/' => !url_safe,
+                    '-' | '_' => url_safe,
+                    _ => false,
+                })
+            }
+            Self::Hex => validate_chars(text, |_, c| c.is_ascii_hexdigit()),
+            Self::Uuid => {
+                validate_chars(text, |i, c| {
+                    if UUID_HYPHENS.contains(&i) {
+                        c == '-'
+                    } else {
+                        c.is_ascii_hexdigit()
+                    }
+                })?;
+                let length = text.chars().count();
+                if length == 36 {
+                    Ok(())
+                } else {
+                    Err(EncodingError::InvalidLength { length })
+                }
+            }
+        }
+    }
+}
+
+impl PhoneticConverter {
+    /// Converts the given text in a machine encoding into a string of code
+    /// words, following the conventions of that encoding.
+    ///
+    /// Letters of case-insensitive encodings are spelled in lowercase, so that
+    /// their case isn't called out. Letters of Base64 text are each preceded
+    /// by "Uppercase" or "Lowercase", in the language of the spelling
+    /// alphabet, since their case is significant. The
+    /// text isn't checked against the encoding; see [`Encoding::validate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to convert into code words.
+    /// * `encoding` - The encoding of the text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{Encoding, PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert_eq!(
+    ///     converter.convert_encoded("0A-1b", Encoding::Uuid),
+    ///     "Zero alfa / One bravo"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn convert_encoded(&self, text: &str, encoding: Encoding) -> String {
+        match encoding {
+            Encoding::Base32 | Encoding::Hex => self.convert(&text.to_lowercase()),
+            Encoding::Base64 => self.convert_case_marked(text),
+            Encoding::Uuid => {
+                let mut result = String::new();
+                for (i, group) in text.split('-').enumerate() {
+                    if i > 0 {
+                        result.push_str(GROUP_BREAK);
+                    }
+                    result.push_str(&self.convert(&group.to_lowercase()));
+                }
+                result
+            }
+        }
+    }
+
+    // Convert text with each letter preceded by its case.
+    fn convert_case_marked(&self, text: &str) -> String {
+        let mut result = String::new();
+        let mut converted = String::new();
+        for c in text.chars() {
+            converted.clear();
+            self.convert_char(c, &mut converted);
+            if converted.is_empty() {
+                continue;
+            }
+            if !result.is_empty() {
+                result.push_str(if self.nonce_form { ", " } else { " " });
+            }
+            let (uppercase, lowercase) = self.alphabet.case_words();
+            if c.is_uppercase() {
+                result.push_str(uppercase);
+                result.push(' ');
+            } else if c.is_lowercase() {
+                result.push_str(lowercase);
+                result.push(' ');
+            }
+            result.push_str(&converted);
+        }
+        result
+    }
+}
+
+// Check each character, along with its index, against the encoding.
+fn validate_chars(text: &str, is_valid: impl Fn(usize, char) -> bool) -> Result<(), EncodingError> {
+    match text.chars().enumerate().find(|&(i, c)| !is_valid(i, c)) {
+        Some((index, character)) => Err(EncodingError::InvalidCharacter { character, index }),
+        None => Ok(()),
+    }
+}
+
+// Check text encoded in blocks of the given size, which may end in "="
+// padding. Unpadded, the final block may only hold one of the given numbers of
+// characters.
+fn validate_padded(
+    text: &str,
+    block: usize,
+    partial: &[usize],
+    is_valid: impl Fn(char) -> bool,
+) -> Result<(), EncodingError> {
+    let data = text.trim_end_matches('=');
+    let data_length = data.chars().count();
+    validate_chars(data, |_, c| is_valid(c)).map_err(|error| match error {
+        EncodingError::InvalidCharacter {
+            character: '=',
+            index,
+        } => EncodingError::InvalidPadding { index },
+        error => error,
+    })?;
+
+    let length = text.chars().count();
+    let remainder = data_length % block;
+    if remainder != 0 && !partial.contains(&remainder) {
+        return Err(EncodingError::InvalidLength { length });
+    }
+    if length > data_length && (remainder == 0 || !length.is_multiple_of(block)) {
+        return Err(EncodingError::InvalidPadding { index: data_length });
+    }
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{CharacterClass, PhoneticConverter};
+
+// The glyphs that are most often mistaken for one another when read from a
+// screen.
+const DEFAULT_GROUPS: [&[&str]; 4] = [&["0", "O"], &["1", "l", "I"], &["5", "S"], &["rn", "m"]];
+
+/// Groups of glyphs that are easily mistaken for one another when read from a
+/// screen, like "0" and "O", or "rn" and "m".
+///
+/// A glyph is one or more characters. The default groups are "0" and "O";
+/// "1", "l", and "I"; "5" and "S"; and "rn" and "m".
+///
+/// # Examples
+///
+/// ```
+/// # use spellabet::{ConfusableGlyphs, PhoneticConverter, SpellingAlphabet};
+/// let glyphs = ConfusableGlyphs::default().group(&["2", "Z"]);
+/// let converter = PhoneticConverter::new(&SpellingAlphabet::default()).confusable_glyphs(glyphs);
+/// assert_eq!(converter.ambiguous_glyphs("Z2ab"), ["Z", "2"]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ConfusableGlyphs {
+    /// The groups of glyphs that look alike.
+    groups: Vec<Vec<String>>,
+}
+
+impl ConfusableGlyphs {
+    /// Creates and returns a new, empty `ConfusableGlyphs` set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { groups: Vec::new() }
+    }
+
+    /// Adds a group of glyphs that look alike. Empty glyphs are ignored.
+    #[must_use]
+    pub fn group(mut self, glyphs: &[&str]) -> Self {
+        let group: Vec<String> = glyphs
+            .iter()
+            .filter(|glyph| !glyph.is_empty())
+            .map(ToString::to_string)
+            .collect();
+        if !group.is_empty() {
+            self.groups.push(group);
+        }
+        self
+    }
+
+    /// Returns the groups of glyphs that look alike.
+    #[must_use]
+    pub fn groups(&self) -> &[Vec<String>] {
+        &self.groups
+    }
+
+    // Find the ambiguous glyphs in the characters, as ranges of character
+    // indexes. Where glyphs overlap, the longest glyph starting first wins.
+    fn find(&self, characters: &[char]) -> Vec<Range<usize>> {
+        let mut found = Vec::new();
+        let mut i = 0;
+        while i < characters.len() {
+            let longest = self
+                .groups
+                .iter()
+                .flatten()
+                .filter_map(|glyph| {
+                    let len = glyph.chars().count();
+                    let window = characters.get(i..i + len)?;
+                    window.iter().copied().eq(glyph.chars()).then_some(len)
+                })
+                .max();
+            match longest {
+                Some(len) => {
+                    found.push(i..i + len);
+                    i += len;
+                }
+                None => i += 1,
+            }
+        }
+        found
+    }
+}
+
+impl Default for ConfusableGlyphs {
+    fn default() -> Self {
+        DEFAULT_GROUPS
+            .iter()
+            .fold(Self::new(), |glyphs, group| glyphs.group(group))
+    }
+}
+
+/// An ambiguous glyph that a token's character is part of, like the "0" that
+/// may be mistaken for an "O".
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AmbiguousGlyph {
+    /// The characters of the glyph.
+    glyph: String,
+    /// Is set when the token's character is the glyph's last.
+    is_last: bool,
+}
+
+impl AmbiguousGlyph {
+    /// Returns the characters of the glyph, like "rn".
+    #[must_use]
+    pub fn glyph(&self) -> &str {
+        &self.glyph
+    }
+
+    /// Returns true if the token's character is the last of the glyph.
+    #[must_use]
+    pub const fn is_last(&self) -> bool {
+        self.is_last
+    }
+
+    /// Returns a label telling the glyph apart from those it resembles, like
+    /// "digit" for "0", "letter" for "O", or "2 letters" for "rn".
+    #[must_use]
+    pub fn label(&self) -> String {
+        let count = self.glyph.chars().count();
+        let mut classes = self.glyph.chars().map(CharacterClass::of);
+        let first = classes.next();
+        let name = match first {
+            Some(CharacterClass::Letter) => "letter",
+            Some(CharacterClass::Digit) => "digit",
+            _ => "symbol",
+        };
+        if count == 1 {
+            name.to_string()
+        } else if classes.all(|class| Some(class) == first) {
+            format!("{count} {name}s")
+        } else {
+            format!("{count} characters")
+        }
+    }
+}
+
+impl PhoneticConverter {
+    /// Configures the glyphs that the current `PhoneticConverter` instance
+    /// treats as easily mistaken for one another when read from a screen.
+    ///
+    /// Ambiguous glyphs are marked in the metadata of [`tokens`](Self::tokens),
+    /// and annotated by [`convert_annotated`](Self::convert_annotated).
+    ///
+    /// # Arguments
+    ///
+    /// * `glyphs` - The [`ConfusableGlyphs`] to look for.
+    #[must_use]
+    pub fn confusable_glyphs(mut self, glyphs: ConfusableGlyphs) -> Self {
+        self.confusable_glyphs = glyphs;
+        self
+    }
+
+    /// Converts the given text into a string of code words like
+    /// [`convert`](Self::convert), following each ambiguous glyph with a label
+    /// that tells it apart from the glyphs it resembles.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to convert into code words.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert_eq!(
+    ///     converter.convert_annotated("O0x"),
+    ///     "OSCAR (letter) Zero (digit) x-ray"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn convert_annotated(&self, text: &str) -> String {
+        let characters: Vec<char> = self.visible_chars(text).collect();
+        let glyphs = self.confusable_glyphs.find(&characters);
+
+        let mut result = String::new();
+        let mut converted = String::new();
+        for (i, c) in characters.iter().enumerate() {
+            converted.clear();
+            self.convert_char(*c, &mut converted);
+            // a skipped character takes its label along with it
+            if converted.is_empty() {
+                continue;
+            }
+            if !result.is_empty() {
+                result.push_str(if self.nonce_form { ", " } else { " " });
+            }
+            result.push_str(&converted);
+            if let Some(glyph) = glyphs.iter().find(|glyph| glyph.end == i + 1) {
+                let glyph = ambiguous_glyph(&characters, glyph, i);
+                result.push_str(" (");
+                result.push_str(&glyph.label());
+                result.push(')');
+            }
+        }
+        result
+    }
+
+    /// Returns the ambiguous glyphs in the given text, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert_eq!(converter.ambiguous_glyphs("I1 burn"), ["I", "1", "rn"]);
+    /// ```
+    #[must_use]
+    pub fn ambiguous_glyphs(&self, text: &str) -> Vec<String> {
+        let characters: Vec<char> = self.visible_chars(text).collect();
+        self.confusable_glyphs
+            .find(&characters)
+            .into_iter()
+            .map(|glyph| characters[glyph].iter().collect())
+            .collect()
+    }
+
+    // Find the ambiguous glyph of each character, for token metadata.
+    pub(crate) fn glyph_of_each(&self, characters: &[char]) -> Vec<Option<AmbiguousGlyph>> {
+        let glyphs = self.confusable_glyphs.find(characters);
+        (0..characters.len())
+            .map(|i| {
+                glyphs
+                    .iter()
+                    .find(|glyph| glyph.contains(&i))
+                    .map(|glyph| ambiguous_glyph(characters, glyph, i))
+            })
+            .collect()
+    }
+}
+
+fn ambiguous_glyph(characters: &[char], glyph: &Range<usize>, index: usize) -> AmbiguousGlyph {
+    AmbiguousGlyph {
+        glyph: characters[glyph.clone()].iter().collect(),
+        is_last: index + 1 == glyph.end,
+    }
+}
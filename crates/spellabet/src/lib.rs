@@ -1,3 +1,4 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(clippy::all)]
 #![warn(clippy::nursery, clippy::pedantic)]
 
@@ -11,9 +12,9 @@
 //! communication needs.
 //!
 //! In operation, spellabet preserves the original capitalization of letters by
-//! returning either lowercase or uppercase code words. It similarly converts
-//! known digits and other symbols into code words, while unrecognized
-//! characters are returned unconverted.
+//! returning either lowercase or uppercase code words, for Latin and Cyrillic
+//! scripts alike. It similarly converts known digits and other symbols into
+//! code words, while unrecognized characters are returned unconverted.
 //!
 //! This library powers the command line utility `spellout`, which provides a
 //! handy interface for phonetic conversions. Check out [spellout on GitHub][]
@@ -35,47 +36,280 @@
 //! ECHO x-ray alfa mike papa lima echo One Two Tree Exclamation
 //! ```
 
-use std::char;
-use std::cmp::Ordering;
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
+use core::ptr;
+#[cfg(feature = "std")]
 use std::collections::HashMap;
-use std::fmt::Write;
+#[cfg(feature = "std")]
+use std::sync::{LazyLock, OnceLock};
 
 use code_words::{
-    DEFAULT_DIGITS_AND_SYMBOLS, JAN_ALPHABET, LAPD_ALPHABET, NATO_ALPHABET, ROYAL_NAVY_ALPHABET,
-    US_FINANCIAL_ALPHABET, WESTERN_UNION_ALPHABET,
+    ARABIC_ALPHABET, ARABIC_DIGITS_AND_SYMBOLS, BULGARIAN_ALPHABET, BULGARIAN_DIGITS_AND_SYMBOLS,
+    DEFAULT_DIGITS_AND_SYMBOLS, DUTCH_DIGITS_AND_SYMBOLS, FORMAL_DIGITS_AND_SYMBOLS,
+    FRENCH_DIGITS_AND_SYMBOLS, GERMAN_DIGITS_AND_SYMBOLS, GREEK_ALPHABET, GREEK_DIGITS_AND_SYMBOLS,
+    HEBREW_ALPHABET, HEBREW_DIGITS_AND_SYMBOLS, ICAO_1951_DRAFT_ALPHABET,
+    ITALIAN_DIGITS_AND_SYMBOLS, ITU_1927_ALPHABET, ITU_1938_ALPHABET, JAN_ALPHABET,
+    JAPANESE_DIGITS_AND_SYMBOLS, KOREAN_ALPHABET, KOREAN_DIGITS_AND_SYMBOLS, LAPD_ALPHABET,
+    MILITARY_DIGITS_AND_SYMBOLS, NATO_ALPHABET, PROGRAMMER_DIGITS_AND_SYMBOLS, RAF_1924_ALPHABET,
+    RAF_1942_ALPHABET, ROYAL_NAVY_ALPHABET, RUSSIAN_ALPHABET, RUSSIAN_DIGITS_AND_SYMBOLS,
+    SPANISH_DIGITS_AND_SYMBOLS, Table, UKRAINIAN_ALPHABET, UKRAINIAN_DIGITS_AND_SYMBOLS,
+    US_FINANCIAL_ALPHABET, US_TELEPHONE_ALPHABET, WABUN_ALPHABET, WESTERN_UNION_ALPHABET,
 };
 use convert_case::{Case, Casing};
+use decompose::{Mark, VisibleChars, decompose, decompose_syllable, fold_variant, in_script_of};
+
+// The map type of overrides and their index. Without the `std` feature, there's
+// no source of randomness for hashing, so an ordered map is used instead.
+#[cfg(feature = "std")]
+pub(crate) type Map<K, V> = std::collections::HashMap<K, V>;
+#[cfg(not(feature = "std"))]
+pub(crate) type Map<K, V> = alloc::collections::BTreeMap<K, V>;
 
+pub use checksum::{Checksum, ChecksumError};
+pub use code_word::{CodeWord, Pronunciation, WordStyle};
+pub use confusion::ConfusablePair;
+pub use diff::{Change, CharacterClass};
+pub use encoding::{Encoding, EncodingError};
+pub use glyphs::{AmbiguousGlyph, ConfusableGlyphs};
+pub use morse::{MorseElement, MorseEncoder};
+pub use ssml::{SsmlOptions, UppercaseMarkup};
+pub use token::Token;
+pub use validation::Finding;
+
+mod checksum;
+mod code_word;
 mod code_words;
+mod confusion;
+mod decompose;
+mod diff;
+mod encoding;
+mod glyphs;
+mod morse;
+mod ssml;
+mod token;
+mod validation;
 
 /// A phonetic converter.
 #[derive(Clone, Debug)]
 pub struct PhoneticConverter {
-    /// The map of characters to code words.
-    conversion_map: HashMap<char, String>,
+    /// The code words of the overrides, layered over the static code word
+    /// tables of the alphabet and vocabulary.
+    override_map: Map<char, CodeWord>,
+    /// The spelling alphabet providing the letter code words.
+    alphabet: SpellingAlphabet,
+    /// The vocabulary providing the digit and symbol code words.
+    vocabulary: SymbolVocabulary,
+    /// The overrides as given, applied on top of the alphabet and vocabulary.
+    overrides: Map<char, String>,
+    /// How the overrides' code words are normalized.
+    override_case: OverrideCase,
     /// Is set when the code word output will be in "nonce form".
     nonce_form: bool,
+    /// Is set when accented letters are followed by code words for their marks.
+    mark_accents: bool,
+    /// The phrasing used for code words in "nonce form".
+    nonce_style: NonceStyle,
+    /// How compound code words are rendered.
+    word_style: WordStyle,
+    /// How characters without a code word are handled.
+    unknown_chars: UnknownChars,
+    /// The characters of each code word in the override map, keyed by
+    /// `index_key()`.
+    override_index: Map<String, char>,
+    /// Is set when code words are replaced by their pronunciation respellings.
+    respell: bool,
+    /// The glyphs that are easily mistaken for one another.
+    confusable_glyphs: ConfusableGlyphs,
+    /// The current character mappings as strings, built the first time
+    /// they're requested.
+    #[cfg(feature = "std")]
+    conversion_map: OnceLock<HashMap<char, String>>,
+}
+
+// The code words spelling a single character.
+enum Spelled<'a> {
+    // A code word, followed by the code words of any marks
+    CodeWord(Spelling<'a>),
+    // The code words of a syllable block's component letters
+    Syllable(Spelling<'a>),
+}
+
+// The code words of a spelled character, along with the language of each.
+// A decomposed character also keeps its base letter, and the mark spelled by
+// each code word after the first.
+#[derive(Default)]
+struct Spelling<'a> {
+    words: Vec<Cow<'a, str>>,
+    languages: Vec<&'static str>,
+    base: Option<char>,
+    marks: Vec<Mark>,
+}
+
+impl<'a> Spelling<'a> {
+    fn push(&mut self, word: Cow<'a, str>, language: &'static str) {
+        self.words.push(word);
+        self.languages.push(language);
+    }
+
+    fn push_mark(&mut self, mark: Mark, word: Cow<'a, str>, language: &'static str) {
+        self.push(word, language);
+        self.marks.push(mark);
+    }
+}
+
+// The phrasing of nonce form output, which follows the alphabet's language.
+#[derive(Clone, Copy, Debug)]
+enum NonceStyle {
+    // "'A' as in ALFA", with the words for "as in"
+    AsIn(&'static str),
+    // "朝日のア"
+    No,
 }
 
 /// A spelling alphabet.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
 pub enum SpellingAlphabet {
+    /// The Arabic letter names.
+    Arabic,
+    /// The Bulgarian spelling alphabet (Cyrillic script).
+    Bulgarian,
+    /// The Greek spelling alphabet. Latin letters are spelled using the NATO
+    /// alphabet, so that mixed Greek and Latin text can be told apart.
+    Greek,
+    /// The Hebrew letter names.
+    Hebrew,
+    /// The 1951 ICAO (International Civil Aviation Organization) draft
+    /// spelling alphabet.
+    Icao1951Draft,
+    /// The 1927 ITU (International Telecommunication Union) spelling alphabet.
+    Itu1927,
+    /// The 1938 ITU (International Telecommunication Union) spelling alphabet.
+    Itu1938,
     /// The JAN (Joint Army/Navy) spelling alphabet.
     Jan,
+    /// The Korean Hangul jamo names. Syllable blocks are spelled as their
+    /// component jamo, grouped per syllable.
+    Korean,
     /// The LAPD (Los Angeles Police Department) spelling alphabet.
     Lapd,
     /// The NATO (North Atlantic Treaty Organization) spelling alphabet.
     /// This is the default.
     #[default]
     Nato,
+    /// The RAF (Royal Air Force) spelling alphabet used from 1924 to 1942.
+    Raf1924,
+    /// The RAF (Royal Air Force) spelling alphabet used from 1942 to 1943.
+    Raf1942,
     /// The Royal Navy spelling alphabet.
     RoyalNavy,
+    /// The Russian spelling alphabet (Cyrillic script).
+    Russian,
+    /// The Ukrainian spelling alphabet (Cyrillic script).
+    Ukrainian,
     /// The United States Financial Industry spelling alphabet.
     UsFinancial,
+    /// The United States telephone spelling alphabet of the 1920s.
+    UsTelephone,
+    /// The Japanese Wabun (i-ro-ha) spelling alphabet. Hiragana and katakana
+    /// share code words, and nonce form follows the "Asahi no A" phrasing,
+    /// naming voiced kana like "Kawase no Ka ni dakuten".
+    Wabun,
     /// The Western Union spelling alphabet.
     WesternUnion,
 }
 
+/// The era in which a spelling alphabet is, or was, in use.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Era {
+    /// The alphabet is in use today.
+    Current,
+    /// The alphabet has been superseded, and is mostly of interest for
+    /// training or decoding archived transcripts.
+    Historical,
+}
+
+/// A vocabulary of code words for digits and symbols.
+///
+/// Each [`SpellingAlphabet`] uses the vocabulary of its own language by
+/// default, like Russian for the Russian alphabet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum SymbolVocabulary {
+    /// Arabic digit and symbol names, like "تسعة".
+    Arabic,
+    /// Bulgarian digit and symbol names, like "Девет".
+    Bulgarian,
+    /// Dutch digit and symbol names, like "Uitroepteken".
+    Dutch,
+    /// English digit and symbol names, like "Exclamation".
+    /// This is the default.
+    #[default]
+    English,
+    /// Formal digit and symbol names following their Unicode names, like
+    /// "Solidus".
+    Formal,
+    /// French digit and symbol names, like "Neuf".
+    French,
+    /// German digit and symbol names, like "Ausrufezeichen".
+    German,
+    /// Greek digit and symbol names, like "Εννέα".
+    Greek,
+    /// Hebrew digit and symbol names, like "תשע".
+    Hebrew,
+    /// Italian digit and symbol names, like "Nove".
+    Italian,
+    /// Japanese digit and symbol names, like "キュウ".
+    Japanese,
+    /// Korean digit and symbol names, like "아홉".
+    Korean,
+    /// Military radiotelephony digit and symbol names, like "Niner" and
+    /// "Stroke".
+    Military,
+    /// Programmer jargon for digits and symbols, like "Bang" and "Splat".
+    Programmer,
+    /// Russian digit and symbol names, like "Девять".
+    Russian,
+    /// Spanish digit and symbol names, like "Exclamación".
+    Spanish,
+    /// Ukrainian digit and symbol names, like "Дев'ять".
+    Ukrainian,
+}
+
+/// A policy for normalizing the capitalization of override code words.
+///
+/// Whichever policy is used, code words are still converted to uppercase or
+/// lowercase to match the case of each converted character.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum OverrideCase {
+    /// Join the words with each one capitalized, so "new york" becomes one
+    /// word. This is the default.
+    #[default]
+    Pascal,
+    /// Keep the code words exactly as given, like "O'Brien".
+    Preserve,
+    /// Capitalize the first letter of each word, keeping the hyphens and
+    /// spaces between them, like "X-Ray" for "x-ray".
+    Title,
+}
+
+/// A policy for characters that can't be converted, like those without a code
+/// word.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum UnknownChars {
+    /// Keep the characters unconverted in the output.
+    /// This is the default.
+    #[default]
+    Keep,
+    /// Leave the characters out of the output.
+    Skip,
+}
+
 impl PhoneticConverter {
     /// Creates and returns a new instance of `PhoneticConverter` using the
     /// desired spelling alphabet character mappings.
@@ -94,26 +328,263 @@ impl PhoneticConverter {
     /// ```
     #[must_use]
     pub fn new(alphabet: &SpellingAlphabet) -> Self {
-        let conversion_map = alphabet.initialize();
-
         Self {
-            conversion_map,
+            override_map: Map::new(),
+            alphabet: *alphabet,
+            vocabulary: alphabet.default_vocabulary(),
+            overrides: Map::new(),
+            override_case: OverrideCase::default(),
             nonce_form: false,
+            mark_accents: false,
+            nonce_style: alphabet.nonce_style(),
+            word_style: WordStyle::default(),
+            unknown_chars: UnknownChars::default(),
+            override_index: Map::new(),
+            respell: false,
+            confusable_glyphs: ConfusableGlyphs::default(),
+            #[cfg(feature = "std")]
+            conversion_map: OnceLock::new(),
+        }
+    }
+
+    /// Returns a shared `PhoneticConverter` for the given [`SpellingAlphabet`],
+    /// with the default settings.
+    ///
+    /// Each shared converter is created the first time it's requested, and is
+    /// then reused for the rest of the program. Cloning it is cheap, since the
+    /// built-in code words are read from static tables. Overrides and other
+    /// settings can then be applied to the clone; overrides are layered over
+    /// the static tables rather than copying them.
+    ///
+    /// This function is only available with the `std` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `alphabet` - The [`SpellingAlphabet`] to use for character
+    ///   conversions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    ///
+    /// let converter = PhoneticConverter::shared(SpellingAlphabet::Nato);
+    /// assert_eq!(converter.convert("Hi"), "HOTEL india");
+    ///
+    /// let mut overrides_map = HashMap::new();
+    /// overrides_map.insert('h', "Hawaii".to_string());
+    /// let custom = converter.clone().with_overrides(overrides_map);
+    /// assert_eq!(custom.convert("Hi"), "HAWAII india");
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn shared(alphabet: SpellingAlphabet) -> &'static Self {
+        macro_rules! shared {
+            ($($variant:ident),+ $(,)?) => {
+                match alphabet {
+                    $(SpellingAlphabet::$variant => {
+                        static CONVERTER: LazyLock<PhoneticConverter> =
+                            LazyLock::new(|| PhoneticConverter::new(&SpellingAlphabet::$variant));
+                        &CONVERTER
+                    })+
+                }
+            };
         }
+
+        shared!(
+            Arabic,
+            Bulgarian,
+            Greek,
+            Hebrew,
+            Icao1951Draft,
+            Itu1927,
+            Itu1938,
+            Jan,
+            Korean,
+            Lapd,
+            Nato,
+            Raf1924,
+            Raf1942,
+            RoyalNavy,
+            Russian,
+            Ukrainian,
+            UsFinancial,
+            UsTelephone,
+            Wabun,
+            WesternUnion,
+        )
     }
 
     /// Get the current character mappings of the `PhoneticConverter` instance.
+    ///
+    /// The map is built the first time it's requested and then kept, while
+    /// conversions read the static code word tables directly.
+    ///
+    /// This function is only available with the `std` feature; see
+    /// [`PhoneticConverter::code_words`] for the mappings as [`CodeWord`]s.
+    #[cfg(feature = "std")]
     #[must_use]
-    pub const fn mappings(&self) -> &HashMap<char, String> {
-        &self.conversion_map
+    pub fn mappings(&self) -> &HashMap<char, String> {
+        self.conversion_map.get_or_init(|| {
+            self.entries()
+                .map(|(character, code_word)| (character, code_word.as_str().to_string()))
+                .collect()
+        })
+    }
+
+    /// Returns the current character mappings of the `PhoneticConverter`
+    /// instance as [`CodeWord`]s.
+    ///
+    /// The code words of the built-in alphabets are borrowed from static
+    /// tables, so only the map itself is allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::Nato)
+    ///     .with_overrides([('n', "New York".to_string())]);
+    /// let code_words = converter.code_words();
+    /// assert_eq!(code_words[&'a'], "Alfa");
+    /// assert_eq!(code_words[&'n'].words(), ["New", "York"]);
+    /// ```
+    #[must_use]
+    pub fn code_words(&self) -> BTreeMap<char, CodeWord> {
+        self.entries()
+            .map(|(character, code_word)| (character, code_word.clone()))
+            .collect()
+    }
+
+    // Iterate over the characters of text that are converted, without any
+    // bidirectional controls that would be dropped, with combining accents
+    // composed with their base letters, and with vowel points folded into
+    // their letters.
+    fn visible_chars<'a>(&'a self, text: &'a str) -> impl Iterator<Item = char> + 'a {
+        VisibleChars::new(text.chars(), |c| self.lookup(c).is_some())
+    }
+
+    // Look up the code word of a normalized key, preferring any override.
+    fn lookup(&self, key: char) -> Option<&CodeWord> {
+        self.lookup_entry(key).map(|(code_word, _)| code_word)
+    }
+
+    // Look up the code word of a normalized key along with the language it's
+    // in. Overrides are taken to be in the alphabet's language.
+    fn lookup_entry(&self, key: char) -> Option<(&CodeWord, &'static str)> {
+        self.override_map.get(&key).map_or_else(
+            || {
+                self.alphabet
+                    .lookup_with(self.vocabulary, key)
+                    .map(|(code_word, language)| (code_word as &CodeWord, language))
+            },
+            |code_word| Some((code_word, self.alphabet.language())),
+        )
+    }
+
+    // Iterate over the current character mappings, with the overrides in
+    // place of the code words they replace.
+    fn entries(&self) -> impl Iterator<Item = (char, &CodeWord)> {
+        self.override_map
+            .iter()
+            .map(|(character, code_word)| (*character, code_word))
+            .chain(
+                self.alphabet
+                    .entries_with(self.vocabulary)
+                    .filter(|(character, _)| !self.override_map.contains_key(character))
+                    .map(|(character, code_word)| (character, code_word as &CodeWord)),
+            )
+    }
+
+    /// Returns the code word of the given character, if it has one, in the
+    /// case of the character.
+    ///
+    /// Characters are looked up and cased the same way as when converting, so
+    /// an uppercase letter gets an uppercase code word. Characters that are
+    /// only spelled through their parts, like accented letters or syllable
+    /// blocks, have no code word of their own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert_eq!(converter.code_word('A'), Some("ALFA"));
+    /// assert_eq!(converter.code_word('a'), Some("alfa"));
+    /// assert_eq!(converter.code_word('9'), Some("Niner"));
+    /// assert_eq!(converter.code_word('€'), None);
+    /// ```
+    #[must_use]
+    pub fn code_word(&self, character: char) -> Option<&str> {
+        self.lookup(normalize_key(character))
+            .map(|code_word| code_word.cased(character))
+    }
+
+    /// Returns the character spelled by the given code word, if any.
+    ///
+    /// Code words are matched ignoring case, and ignoring the spaces, hyphens,
+    /// and underscores between their words, so the code word can be given in
+    /// any [`WordStyle`]. Letters are returned in lowercase. When characters
+    /// share a code word, the first in the order of
+    /// [`sorted_mappings`](Self::sorted_mappings) is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert_eq!(converter.character_for("ALFA"), Some('a'));
+    /// assert_eq!(converter.character_for("double quote"), Some('"'));
+    /// assert_eq!(converter.character_for("Apple"), None);
+    /// ```
+    #[must_use]
+    pub fn character_for(&self, code_word: &str) -> Option<char> {
+        let key = index_key(code_word);
+        self.alphabet
+            .tables_with(self.vocabulary)
+            .flat_map(|table| table.characters_for(&key))
+            .chain(self.override_index.get(&key).copied())
+            // a character's code word may be in a table it's shadowed in
+            .filter(|character| {
+                self.lookup(*character)
+                    .is_some_and(|word| index_key(word.as_str()) == key)
+            })
+            .min_by(|a, b| custom_char_ordering(*a, *b))
+    }
+
+    /// Returns true if the given character has a code word of its own. See
+    /// [`code_word`](Self::code_word) for how characters are looked up.
+    #[must_use]
+    pub fn contains(&self, character: char) -> bool {
+        self.lookup(normalize_key(character)).is_some()
+    }
+
+    /// Returns the group of the given character, or
+    /// [`CharacterClass::Unknown`] if it has no code word of its own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{CharacterClass, PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert_eq!(converter.classify('Q'), CharacterClass::Letter);
+    /// assert_eq!(converter.classify('ж'), CharacterClass::Unknown);
+    /// ```
+    #[must_use]
+    pub fn classify(&self, character: char) -> CharacterClass {
+        if self.contains(character) {
+            CharacterClass::of(character)
+        } else {
+            CharacterClass::Unknown
+        }
     }
 
     /// Configures the current `PhoneticConverter` instance to either output
     /// code words in "nonce form" or not, based on the given boolean value.
     ///
     /// Nonce form means each letter character is expanded into the form "'A' as
-    /// in ALFA". Digits and symbols are always returned using the normal output
-    /// format.
+    /// in ALFA", or "朝日のア" for the Wabun alphabet. Digits and symbols are
+    /// always returned using the normal output format.
     ///
     /// # Arguments
     ///
@@ -137,14 +608,177 @@ impl PhoneticConverter {
         self
     }
 
-    /// Modifies the conversion map of the current `PhoneticConverter` instance
-    /// by adding or replacing mappings based on the given overrides map.
+    /// Configures the current `PhoneticConverter` instance to either follow
+    /// accented letters with the code words of their marks or not, based on
+    /// the given boolean value.
+    ///
+    /// Accented letters without a mapping of their own are always converted
+    /// using the code word of their base letter, so "ά" is spelled like "α".
+    /// Marking accents appends the code word for each mark, like the Greek
+    /// tonos, when the current alphabet defines one. Kana modifiers, like the
+    /// dakuten, change the sound of the base kana and are always spelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `mark_accents` - If true, code words for marks are appended.
+    ///   Otherwise, only the base letter is spelled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::Greek);
+    /// assert_eq!(converter.convert("ά"), "αστήρ");
+    ///
+    /// let converter = converter.mark_accents(true);
+    /// assert_eq!(converter.convert("ά"), "αστήρ τόνος");
+    /// ```
+    #[must_use]
+    pub const fn mark_accents(mut self, mark_accents: bool) -> Self {
+        self.mark_accents = mark_accents;
+        self
+    }
+
+    /// Configures the current `PhoneticConverter` instance to render compound
+    /// code words, like "`DoubleQuote`", in the given style. This applies to
+    /// both converted text and dumped alphabets.
     ///
     /// # Arguments
     ///
-    /// * `overrides_map` - The desired character to code word mappings to
-    ///   override. The capitalization of the keys and values will be
-    ///   automatically normalized. For Unicode keys, normalization only
+    /// * `word_style` - The [`WordStyle`] to render code words in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet, WordStyle};
+    /// let converter =
+    ///     PhoneticConverter::new(&SpellingAlphabet::default()).word_style(&WordStyle::Spaced);
+    /// assert_eq!(converter.convert("a\"<"), "alfa double quote less than");
+    /// ```
+    #[must_use]
+    pub const fn word_style(mut self, word_style: &WordStyle) -> Self {
+        self.word_style = *word_style;
+        self
+    }
+
+    /// Configures how the current `PhoneticConverter` instance handles
+    /// characters without a code word, which are kept unconverted by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `unknown_chars` - The [`UnknownChars`] policy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet, UnknownChars};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// assert_eq!(converter.convert("a€b"), "alfa € bravo");
+    ///
+    /// let converter = converter.unknown_chars(&UnknownChars::Skip);
+    /// assert_eq!(converter.convert("a€b"), "alfa bravo");
+    /// ```
+    #[must_use]
+    pub const fn unknown_chars(mut self, unknown_chars: &UnknownChars) -> Self {
+        self.unknown_chars = *unknown_chars;
+        self
+    }
+
+    /// Configures the current `PhoneticConverter` instance to either replace
+    /// code words with their pronunciation respellings or not, based on the
+    /// given boolean value.
+    ///
+    /// Code words without a known pronunciation are kept as they are. See
+    /// [`CodeWord::pronunciation`] for which pronunciations are known.
+    ///
+    /// # Arguments
+    ///
+    /// * `respell` - If true, code words are replaced by their respellings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default()).respell(true);
+    /// assert_eq!(converter.convert("Ab9"), "AL FAH brah voh NIN ER");
+    /// ```
+    #[must_use]
+    pub const fn respell(mut self, respell: bool) -> Self {
+        self.respell = respell;
+        self
+    }
+
+    /// Configures the current `PhoneticConverter` instance to use the given
+    /// vocabulary for digits and symbols, in place of the default vocabulary of
+    /// its spelling alphabet.
+    ///
+    /// Digit code words that belong to the spelling alphabet itself, like the
+    /// NATO "Niner", are only used along with the alphabet's own vocabulary.
+    /// Any overrides are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `vocabulary` - The [`SymbolVocabulary`] to use for digits and
+    ///   symbols.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet, SymbolVocabulary};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default())
+    ///     .symbol_vocabulary(&SymbolVocabulary::German);
+    /// assert_eq!(converter.convert("a9!"), "alfa Neun Ausrufezeichen");
+    /// ```
+    #[must_use]
+    #[cfg_attr(not(feature = "std"), allow(clippy::missing_const_for_fn))]
+    pub fn symbol_vocabulary(mut self, vocabulary: &SymbolVocabulary) -> Self {
+        self.vocabulary = *vocabulary;
+        // The string mappings are rebuilt with the new vocabulary when they're
+        // next requested.
+        #[cfg(feature = "std")]
+        self.conversion_map.take();
+        self
+    }
+
+    /// Configures how the current `PhoneticConverter` instance normalizes the
+    /// capitalization of override code words. This applies to overrides given
+    /// both before and after it's set.
+    ///
+    /// # Arguments
+    ///
+    /// * `override_case` - The [`OverrideCase`] policy to use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// # use spellabet::{OverrideCase, PhoneticConverter, SpellingAlphabet};
+    ///
+    /// let mut overrides_map = HashMap::new();
+    /// overrides_map.insert('n', "New York".to_string());
+    ///
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default())
+    ///     .with_overrides(overrides_map)
+    ///     .override_case(&OverrideCase::Title);
+    /// assert_eq!(converter.convert("Nn"), "NEW YORK new york");
+    /// ```
+    #[must_use]
+    pub fn override_case(mut self, override_case: &OverrideCase) -> Self {
+        self.override_case = *override_case;
+        self.apply_overrides();
+        self
+    }
+
+    /// Modifies the character mappings of the current `PhoneticConverter`
+    /// instance by adding or replacing mappings based on the given overrides.
+    ///
+    /// # Arguments
+    ///
+    /// * `overrides` - The desired character to code word mappings to
+    ///   override, like a `HashMap<char, String>` or an array of pairs. The
+    ///   capitalization of the keys and values will be automatically
+    ///   normalized, with the values following the
+    ///   [`OverrideCase`] policy. For Unicode keys, normalization only
     ///   lowercases when the result is a single Unicode scalar; otherwise the
     ///   original key is preserved.
     ///
@@ -182,19 +816,37 @@ impl PhoneticConverter {
     /// AFTER: apple banana charlie delta
     /// ```
     #[must_use]
-    pub fn with_overrides(mut self, overrides_map: HashMap<char, String>) -> Self {
-        let normalized_overrides: HashMap<char, String> = overrides_map
-            .into_iter()
-            .map(|(k, v)| (normalize_key(k), v.to_case(Case::Pascal)))
-            .collect();
-
-        self.conversion_map.extend(normalized_overrides);
+    pub fn with_overrides(mut self, overrides: impl IntoIterator<Item = (char, String)>) -> Self {
+        self.overrides
+            .extend(overrides.into_iter().map(|(k, v)| (normalize_key(k), v)));
+        self.apply_overrides();
         self
     }
 
+    // Layers the overrides over the static code word tables, normalized by the
+    // current override case policy.
+    fn apply_overrides(&mut self) {
+        if self.overrides.is_empty() {
+            return;
+        }
+        #[cfg(feature = "std")]
+        self.conversion_map.take();
+        self.override_map = self
+            .overrides
+            .iter()
+            .map(|(k, v)| (*k, self.override_case.code_word(v)))
+            .collect();
+        self.override_index = reverse_index(self.override_map.iter().map(|(k, v)| (*k, v)));
+    }
+
     /// Converts the given text into a string of code words using the current
     /// character mappings of the `PhoneticConverter` instance.
     ///
+    /// Code words are always returned in the logical order of the text, even
+    /// for right-to-left scripts. Bidirectional control characters, like the
+    /// right-to-left mark, are dropped unless they have a code word of their
+    /// own, so that they can't garble the direction of the output.
+    ///
     /// # Arguments
     ///
     /// * `text` - The text to convert into code words.
@@ -209,42 +861,263 @@ impl PhoneticConverter {
     #[must_use]
     pub fn convert(&self, text: &str) -> String {
         let mut result = String::new();
+        // writing to a String never fails
+        let _ = self.write_converted(text, &mut result);
+        result
+    }
+
+    /// Converts the given text into code words like [`convert`](Self::convert),
+    /// writing them to the given writer instead of returning a new string.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to convert into code words.
+    /// * `writer` - The output destination.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if writing to the provided writer
+    /// fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    ///
+    /// let mut output = String::from("Hi: ");
+    /// converter.write_converted("Hi", &mut output)?;
+    /// assert_eq!(output, "Hi: HOTEL india");
+    /// # Ok::<(), std::fmt::Error>(())
+    /// ```
+    pub fn write_converted(&self, text: &str, writer: &mut impl Write) -> fmt::Result {
+        let mut converted = String::new();
+        let mut is_first = true;
 
-        for (i, c) in text.chars().enumerate() {
+        for c in self.visible_chars(text) {
+            converted.clear();
+            self.convert_char(c, &mut converted);
+            if converted.is_empty() {
+                continue;
+            }
             // add separator between converted characters
-            if i != 0 {
-                if self.nonce_form {
-                    result.push_str(", ");
-                } else {
-                    result.push(' ');
-                }
+            if !is_first {
+                writer.write_str(if self.nonce_form { ", " } else { " " })?;
             }
-            self.convert_char(c, &mut result);
+            writer.write_str(&converted)?;
+            is_first = false;
         }
-        result
+        Ok(())
     }
 
     fn convert_char(&self, character: char, result: &mut String) {
-        match self.conversion_map.get(&normalize_key(character)) {
-            Some(word) => {
-                let code_word = match character {
-                    _ if character.is_lowercase() => word.to_lowercase(),
-                    _ if character.is_uppercase() => word.to_uppercase(),
-                    _ => word.clone(),
-                };
+        // ASCII characters have no marks and aren't syllable blocks, so their
+        // code word is looked up and written directly
+        if character.is_ascii() {
+            match self.lookup(character.to_ascii_lowercase()) {
+                Some(word) => {
+                    let code_word = self.render_cased(character, word, self.word_style);
+                    self.push_code_word(character, None, &code_word, result);
+                }
+                None => {
+                    if self.unknown_chars == UnknownChars::Keep {
+                        result.push(character);
+                    }
+                }
+            }
+            return;
+        }
 
-                if self.nonce_form && character.is_alphabetic() {
-                    write!(result, "'{character}' as in {code_word}").unwrap();
+        match self.spell_char(character, self.word_style) {
+            Some(Spelled::CodeWord(spelling)) => {
+                let (code_word, mark_words) = spelling
+                    .words
+                    .split_first()
+                    .expect("spelled by a code word");
+                self.push_code_word(character, spelling.base, code_word, result);
+                for (mark_word, mark) in mark_words.iter().zip(&spelling.marks) {
+                    match self.nonce_style {
+                        NonceStyle::No if self.nonce_form => result.push_str(mark.particle()),
+                        _ => result.push(' '),
+                    }
+                    result.push_str(mark_word);
+                }
+            }
+            Some(Spelled::Syllable(spelling)) => {
+                let jamo_words = spelling.words.join(" ");
+                match self.nonce_style {
+                    NonceStyle::AsIn(as_in) if self.nonce_form => {
+                        write!(result, "'{character}' {as_in} {jamo_words}").unwrap();
+                    }
+                    NonceStyle::No if self.nonce_form => {
+                        write!(result, "{jamo_words}の{character}").unwrap();
+                    }
+                    _ => write!(result, "({jamo_words})").unwrap(),
+                }
+            }
+            None => {
+                if self.unknown_chars == UnknownChars::Keep {
+                    result.push(character);
+                }
+            }
+        }
+    }
+
+    // Write the code word of a character, or of its base letter. Japanese
+    // nonce form names the base letter, and adds the marks after it.
+    fn push_code_word(
+        &self,
+        character: char,
+        base: Option<char>,
+        code_word: &str,
+        result: &mut String,
+    ) {
+        if self.nonce_form && character.is_alphabetic() {
+            match self.nonce_style {
+                NonceStyle::AsIn(as_in) => {
+                    write!(result, "'{character}' {as_in} {code_word}").unwrap();
+                }
+                NonceStyle::No => {
+                    result.push_str(code_word);
+                    result.push('の');
+                    result.push(base.map_or(character, |base| in_script_of(base, character)));
+                }
+            }
+        } else {
+            result.push_str(code_word);
+        }
+    }
+
+    // Spell a character as the code word of the character itself, or of its
+    // base letter followed by those of its marks. Otherwise, spell a syllable
+    // block as the code words of its component jamo.
+    fn spell_char(&self, character: char, style: WordStyle) -> Option<Spelled<'_>> {
+        let key = normalize_key(character);
+        let lookup = self
+            .lookup_entry(key)
+            .map(|entry| (entry, None, [].as_slice()))
+            .or_else(|| {
+                let decomposition = decompose(key)?;
+                let marks = if decomposition.is_accent && !self.mark_accents {
+                    &[]
                 } else {
-                    result.push_str(&code_word);
+                    decomposition.marks
+                };
+                let entry = self.lookup_entry(decomposition.base)?;
+                Some((entry, Some(decomposition.base), marks))
+            });
+
+        match lookup {
+            Some(((word, language), base, marks)) => {
+                let mut spelling = Spelling {
+                    base,
+                    ..Spelling::default()
+                };
+                spelling.push(self.render_cased(character, word, style), language);
+                for mark in marks {
+                    match mark {
+                        Mark::Key(key) => {
+                            if let Some((word, language)) = self.lookup_entry(*key) {
+                                let word = self.render_cased(character, word, style);
+                                spelling.push_mark(*mark, word, language);
+                            }
+                        }
+                        // fixed mark words are in the language of their letter
+                        Mark::Word(word) => {
+                            let word = Cow::Owned(match_case(character, word));
+                            spelling.push_mark(*mark, word, language);
+                        }
+                    }
                 }
+                Some(Spelled::CodeWord(spelling))
             }
-            None => result.push(character),
+            None => self.spell_syllable(key, style).map(Spelled::Syllable),
         }
     }
 
+    // Render a code word in the given style, or as its respelling.
+    fn render<'a>(&self, word: &'a CodeWord, style: WordStyle) -> Cow<'a, str> {
+        match word.pronunciation() {
+            Some(pronunciation) if self.respell => Cow::Borrowed(pronunciation.respelling()),
+            _ => word.render(style),
+        }
+    }
+
+    // Render a code word like `render()`, in the case of the given character.
+    // Code words as written borrow their precomputed case forms.
+    fn render_cased<'a>(
+        &self,
+        character: char,
+        word: &'a CodeWord,
+        style: WordStyle,
+    ) -> Cow<'a, str> {
+        if style == WordStyle::Pascal && !self.respell {
+            Cow::Borrowed(word.cased(character))
+        } else {
+            Cow::Owned(match_case(character, &self.render(word, style)))
+        }
+    }
+
+    // Spell a Hangul syllable block as the code words of its component jamo,
+    // provided that every jamo has a code word.
+    fn spell_syllable(&self, character: char, style: WordStyle) -> Option<Spelling<'_>> {
+        let (initial, medial, last) = decompose_syllable(character)?;
+        let mut spelling = Spelling::default();
+        for jamo in [Some(initial), Some(medial), last].into_iter().flatten() {
+            let (word, language) = self.lookup_entry(jamo)?;
+            spelling.push(self.render(word, style), language);
+        }
+        Some(spelling)
+    }
+
+    /// Splits the given text into tokens, each holding a character along with
+    /// the code words spelling it, using the current character mappings of
+    /// the `PhoneticConverter` instance.
+    ///
+    /// Tokens are useful for rendering code words in formats other than plain
+    /// text. They follow the same rules as [`convert`](Self::convert), except
+    /// that nonce form isn't applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to split into tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// let tokens = converter.tokens("Hi!");
+    /// assert_eq!(tokens[0].character(), 'H');
+    /// assert_eq!(tokens[0].code_words(), ["HOTEL"]);
+    /// assert_eq!(tokens[2].code_words(), ["Exclamation"]);
+    /// ```
+    #[must_use]
+    pub fn tokens(&self, text: &str) -> Vec<Token> {
+        self.styled_tokens(text, self.word_style)
+    }
+
+    // Split text into tokens, with the code words rendered in the given style.
+    fn styled_tokens(&self, text: &str, style: WordStyle) -> Vec<Token> {
+        let characters: Vec<char> = self.visible_chars(text).collect();
+        let glyphs = self.glyph_of_each(&characters);
+        characters
+            .into_iter()
+            .zip(glyphs)
+            .filter_map(|(c, glyph)| {
+                let spelling = match self.spell_char(c, style) {
+                    Some(Spelled::CodeWord(spelling) | Spelled::Syllable(spelling)) => spelling,
+                    None if self.unknown_chars == UnknownChars::Keep => Spelling::default(),
+                    None => return None,
+                };
+                Some(Token::new(c, spelling, glyph))
+            })
+            .collect()
+    }
+
     /// Writes the current character mappings of the `PhoneticConverter`
-    /// instance to the given writer.
+    /// instance to the given writer, with code words rendered in the current
+    /// [`WordStyle`].
     ///
     /// # Arguments
     ///
@@ -258,6 +1131,9 @@ impl PhoneticConverter {
     /// fails. The specific conditions under which this may occur depend on the
     /// nature of the writer.
     ///
+    /// This function is only available with the `std` feature. Otherwise, see
+    /// [`write_alphabet`](Self::write_alphabet).
+    ///
     /// # Examples
     ///
     /// ```
@@ -278,27 +1154,144 @@ impl PhoneticConverter {
     /// c -> Charlie
     /// ...
     /// ```
+    #[cfg(feature = "std")]
     pub fn dump_alphabet(
         &self,
         mut writer: impl std::io::Write,
         verbose: bool,
     ) -> std::io::Result<()> {
+        let mut output = String::new();
+        self.write_alphabet(&mut output, verbose)
+            .map_err(std::io::Error::other)?;
+        writer.write_all(output.as_bytes())
+    }
+
+    /// Writes the current character mappings of the `PhoneticConverter`
+    /// instance to the given formatter writer, like
+    /// [`dump_alphabet`](Self::dump_alphabet).
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The output destination.
+    /// * `verbose` - If true, writes all characters. Otherwise, writes only
+    ///   letter characters.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if writing to the provided writer
+    /// fails.
+    pub fn write_alphabet(&self, writer: &mut impl Write, verbose: bool) -> fmt::Result {
         for (character, code_word) in self.sorted_mappings() {
             if verbose || character.is_alphabetic() {
+                let code_word = code_word.render(self.word_style);
                 writeln!(writer, "{character} -> {code_word}")?;
             }
         }
         Ok(())
     }
 
+    /// Writes the current character mappings of the `PhoneticConverter`
+    /// instance to the given writer, along with the respelling and IPA
+    /// transcription of each code word with a known pronunciation.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The output destination.
+    /// * `verbose` - If true, dumps all characters. Otherwise, dumps only
+    ///   letter characters.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if writing to the provided writer
+    /// fails.
+    ///
+    /// This function is only available with the `std` feature. Otherwise, see
+    /// [`write_pronunciations`](Self::write_pronunciations).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    ///
+    /// let mut buf = Vec::new();
+    /// converter.dump_pronunciations(&mut buf, false)?;
+    /// let output = String::from_utf8(buf)?;
+    /// assert!(output.starts_with("a -> Alfa      AL FAH"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn dump_pronunciations(
+        &self,
+        mut writer: impl std::io::Write,
+        verbose: bool,
+    ) -> std::io::Result<()> {
+        let mut output = String::new();
+        self.write_pronunciations(&mut output, verbose)
+            .map_err(std::io::Error::other)?;
+        writer.write_all(output.as_bytes())
+    }
+
+    /// Writes the current character mappings of the `PhoneticConverter`
+    /// instance to the given formatter writer, along with their
+    /// pronunciations, like [`dump_pronunciations`](Self::dump_pronunciations).
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The output destination.
+    /// * `verbose` - If true, writes all characters. Otherwise, writes only
+    ///   letter characters.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if writing to the provided writer
+    /// fails.
+    pub fn write_pronunciations(&self, writer: &mut impl Write, verbose: bool) -> fmt::Result {
+        let rows: Vec<_> = self
+            .sorted_mappings()
+            .into_iter()
+            .filter(|(character, _)| verbose || character.is_alphabetic())
+            .map(|(character, code_word)| {
+                let pronunciation = code_word.pronunciation();
+                (
+                    character,
+                    code_word.render(self.word_style).into_owned(),
+                    pronunciation,
+                )
+            })
+            .collect();
+        let width = rows
+            .iter()
+            .map(|(_, code_word, _)| code_word.chars().count())
+            .max()
+            .unwrap_or_default();
+        let respelling_width = rows
+            .iter()
+            .filter_map(|(_, _, pronunciation)| pronunciation.map(|p| p.respelling().len()))
+            .max()
+            .unwrap_or_default();
+
+        for (character, code_word, pronunciation) in rows {
+            match pronunciation {
+                Some(pronunciation) => writeln!(
+                    writer,
+                    "{character} -> {code_word:<width$}  {:<respelling_width$}  {}",
+                    pronunciation.respelling(),
+                    pronunciation.ipa()
+                )?,
+                None => writeln!(writer, "{character} -> {code_word}")?,
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the current character mappings sorted by letters, then digits,
     /// then symbols, with each group sorted by natural character order.
     #[must_use]
-    pub fn sorted_mappings(&self) -> Vec<(char, String)> {
+    pub fn sorted_mappings(&self) -> Vec<(char, CodeWord)> {
         let mut entries: Vec<_> = self
-            .conversion_map
-            .iter()
-            .map(|(character, code_word)| (*character, code_word.clone()))
+            .entries()
+            .map(|(character, code_word)| (character, code_word.clone()))
             .collect();
         entries.sort_by(|(a, _), (b, _)| custom_char_ordering(*a, *b));
         entries
@@ -314,54 +1307,470 @@ impl Default for PhoneticConverter {
 // Sort characters in the order of letters before digits before symbols.
 // Within each group, characters will be sorted in their natural order.
 fn custom_char_ordering(a: char, b: char) -> Ordering {
-    match (
-        a.is_alphabetic(),
-        b.is_alphabetic(),
-        a.is_numeric(),
-        b.is_numeric(),
-    ) {
-        (true, false, _, _) | (false, false, true, false) => Ordering::Less,
-        (false, true, _, _) | (false, false, false, true) => Ordering::Greater,
-        _ => a.cmp(&b),
+    CharacterClass::of(a)
+        .cmp(&CharacterClass::of(b))
+        .then_with(|| a.cmp(&b))
+}
+
+// Build an index from each override's code word to its character. When
+// characters share a code word, the first in sorted order is kept.
+fn reverse_index<'a>(mappings: impl Iterator<Item = (char, &'a CodeWord)>) -> Map<String, char> {
+    let mut index: Map<String, char> = Map::new();
+    for (character, code_word) in mappings {
+        index
+            .entry(index_key(code_word.as_str()))
+            .and_modify(|existing| {
+                if custom_char_ordering(character, *existing) == Ordering::Less {
+                    *existing = character;
+                }
+            })
+            .or_insert(character);
     }
+    index
+}
+
+// Normalize a code word for lookup, ignoring case and word separators.
+fn index_key(code_word: &str) -> String {
+    code_word
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
 }
 
 // Normalize keys to lowercase when that produces a single Unicode scalar.
 // If lowercasing expands to multiple scalars, keep the original character.
+// Positional letter variants are then folded into their base letter.
 fn normalize_key(character: char) -> char {
+    if character.is_ascii() {
+        return character.to_ascii_lowercase();
+    }
     let mut lower = character.to_lowercase();
     let first = lower.next().unwrap_or(character);
     if lower.next().is_none() {
-        first
+        fold_variant(first)
     } else {
         character
     }
 }
 
+// Match the code word's capitalization to that of the original character.
+fn match_case(character: char, word: &str) -> String {
+    match character {
+        _ if character.is_lowercase() => word.to_lowercase(),
+        _ if character.is_uppercase() => word.to_uppercase(),
+        _ => word.to_string(),
+    }
+}
+
 impl SpellingAlphabet {
-    /// Generates and returns a character to code word map based on the current
+    /// All of the available spelling alphabets.
+    pub const ALL: &'static [Self] = &[
+        Self::Arabic,
+        Self::Bulgarian,
+        Self::Greek,
+        Self::Hebrew,
+        Self::Icao1951Draft,
+        Self::Itu1927,
+        Self::Itu1938,
+        Self::Jan,
+        Self::Korean,
+        Self::Lapd,
+        Self::Nato,
+        Self::Raf1924,
+        Self::Raf1942,
+        Self::RoyalNavy,
+        Self::Russian,
+        Self::Ukrainian,
+        Self::UsFinancial,
+        Self::UsTelephone,
+        Self::Wabun,
+        Self::WesternUnion,
+    ];
+
+    /// Returns the era in which the `SpellingAlphabet` is, or was, in use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{Era, SpellingAlphabet};
+    /// let historical: Vec<_> = SpellingAlphabet::ALL
+    ///     .iter()
+    ///     .filter(|alphabet| alphabet.era() == Era::Historical)
+    ///     .collect();
+    /// assert!(historical.contains(&&SpellingAlphabet::Raf1924));
+    /// ```
+    #[must_use]
+    pub const fn era(&self) -> Era {
+        match self {
+            Self::Icao1951Draft
+            | Self::Itu1927
+            | Self::Itu1938
+            | Self::Jan
+            | Self::Raf1924
+            | Self::Raf1942
+            | Self::RoyalNavy
+            | Self::UsTelephone
+            | Self::WesternUnion => Era::Historical,
+            _ => Era::Current,
+        }
+    }
+
+    /// Returns the year the `SpellingAlphabet` was introduced, if known.
+    #[must_use]
+    pub const fn year(&self) -> Option<u16> {
+        match self {
+            Self::Icao1951Draft => Some(1951),
+            Self::Itu1927 => Some(1927),
+            Self::Itu1938 => Some(1938),
+            Self::Jan => Some(1941),
+            Self::Nato => Some(1956),
+            Self::Raf1924 => Some(1924),
+            Self::Raf1942 => Some(1942),
+            Self::RoyalNavy => Some(1914),
+            Self::UsTelephone => Some(1920),
+            Self::WesternUnion => Some(1912),
+            _ => None,
+        }
+    }
+
+    /// Returns the organization or document that defined the
     /// `SpellingAlphabet`.
     #[must_use]
+    pub const fn source(&self) -> &'static str {
+        match self {
+            Self::Arabic => "Arabic letter names",
+            Self::Bulgarian => "Bulgarian spelling alphabet",
+            Self::Greek => "Greek spelling alphabet",
+            Self::Hebrew => "Hebrew letter names",
+            Self::Icao1951Draft => "International Civil Aviation Organization draft",
+            Self::Itu1927 => "International Radiotelegraph Convention of Washington",
+            Self::Itu1938 => "General Radiocommunication Regulations of Cairo",
+            Self::Jan => "Joint Army/Navy",
+            Self::Korean => "Korean Hangul jamo names",
+            Self::Lapd => "Los Angeles Police Department",
+            Self::Nato => "North Atlantic Treaty Organization",
+            Self::Raf1924 | Self::Raf1942 => "Royal Air Force",
+            Self::RoyalNavy => "Royal Navy",
+            Self::Russian => "Russian spelling alphabet",
+            Self::Ukrainian => "Ukrainian spelling alphabet",
+            Self::UsFinancial => "United States Financial Industry",
+            Self::UsTelephone => "United States telephone operators",
+            Self::Wabun => "Japanese Wabun (i-ro-ha) code",
+            Self::WesternUnion => "Western Union",
+        }
+    }
+
+    /// Returns the digit and symbol vocabulary of the `SpellingAlphabet`'s own
+    /// language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{SpellingAlphabet, SymbolVocabulary};
+    /// assert_eq!(
+    ///     SpellingAlphabet::Russian.default_vocabulary(),
+    ///     SymbolVocabulary::Russian
+    /// );
+    /// assert_eq!(
+    ///     SpellingAlphabet::Nato.default_vocabulary(),
+    ///     SymbolVocabulary::English
+    /// );
+    /// ```
+    #[must_use]
+    pub fn default_vocabulary(&self) -> SymbolVocabulary {
+        match self.language() {
+            "ar" => SymbolVocabulary::Arabic,
+            "bg" => SymbolVocabulary::Bulgarian,
+            "el" => SymbolVocabulary::Greek,
+            "he" => SymbolVocabulary::Hebrew,
+            "ja" => SymbolVocabulary::Japanese,
+            "ko" => SymbolVocabulary::Korean,
+            "ru" => SymbolVocabulary::Russian,
+            "uk" => SymbolVocabulary::Ukrainian,
+            _ => SymbolVocabulary::English,
+        }
+    }
+
+    /// Generates and returns a character to code word map of the current
+    /// `SpellingAlphabet` and its default digit and symbol vocabulary.
+    ///
+    /// This function is only available with the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
     pub fn initialize(&self) -> HashMap<char, String> {
-        let mut map: HashMap<char, String> = HashMap::with_capacity(69);
+        self.initialize_with(&self.default_vocabulary())
+    }
 
-        let extend_map = |map: &mut HashMap<char, String>, source_map: &[(char, &str)]| {
-            for (k, v) in source_map {
-                map.insert(*k, (*v).to_string());
-            }
+    /// Generates and returns a character to code word map of the current
+    /// `SpellingAlphabet` and the given digit and symbol vocabulary.
+    ///
+    /// Digit code words that belong to the alphabet itself, like the NATO
+    /// "Niner", are only included along with the alphabet's own vocabulary.
+    ///
+    /// This function is only available with the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn initialize_with(&self, vocabulary: &SymbolVocabulary) -> HashMap<char, String> {
+        self.entries_with(*vocabulary)
+            .map(|(character, code_word)| (character, code_word.as_str().to_string()))
+            .collect()
+    }
+
+    /// Returns a character to [`CodeWord`] map of the current
+    /// `SpellingAlphabet` and its default digit and symbol vocabulary.
+    #[must_use]
+    pub fn code_words(&self) -> BTreeMap<char, CodeWord> {
+        self.code_words_with(&self.default_vocabulary())
+    }
+
+    /// Returns a character to [`CodeWord`] map of the current
+    /// `SpellingAlphabet` and the given digit and symbol vocabulary.
+    ///
+    /// The code words are borrowed from the static tables they're defined in.
+    #[must_use]
+    pub fn code_words_with(&self, vocabulary: &SymbolVocabulary) -> BTreeMap<char, CodeWord> {
+        self.entries_with(*vocabulary)
+            .map(|(character, code_word)| (character, code_word.clone()))
+            .collect()
+    }
+
+    /// Returns the code word for the given character in the `SpellingAlphabet`
+    /// and its default digit and symbol vocabulary, as written, if it has one.
+    ///
+    /// The code word is read directly from the built-in static tables, without
+    /// allocating. Letters are matched regardless of case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::SpellingAlphabet;
+    /// assert_eq!(SpellingAlphabet::Nato.code_word('A'), Some("Alfa"));
+    /// assert_eq!(SpellingAlphabet::Nato.code_word('9'), Some("Niner"));
+    /// assert_eq!(SpellingAlphabet::Lapd.code_word('a'), Some("Adam"));
+    /// assert_eq!(SpellingAlphabet::Nato.code_word('ж'), None);
+    /// ```
+    #[must_use]
+    pub fn code_word(&self, character: char) -> Option<&'static str> {
+        self.lookup_with(self.default_vocabulary(), normalize_key(character))
+            .map(|(code_word, _)| code_word.as_str())
+    }
+
+    // Look up the code word of a normalized key in the alphabet's tables and
+    // the given vocabulary, along with the language it's in. The alphabet's
+    // own table takes precedence over the vocabulary, unless it's the
+    // vocabulary of another language, and the vocabulary takes precedence over
+    // the alphabet that this one builds on.
+    fn lookup_with(
+        self,
+        vocabulary: SymbolVocabulary,
+        key: char,
+    ) -> Option<(&'static CodeWord, &'static str)> {
+        let own = (self.table(), self.language());
+        let symbols = (vocabulary.table(), vocabulary.language());
+        let preferred = if vocabulary == self.default_vocabulary() {
+            [own, symbols]
+        } else {
+            [symbols, own]
         };
+        preferred
+            .into_iter()
+            .chain(self.base().map(|base| (base.table(), base.language())))
+            .find_map(|(table, language)| Some((table.get(key)?, language)))
+    }
+
+    // Iterate over the characters and code words of the alphabet's tables and
+    // the given vocabulary, without those that are shadowed by another table.
+    fn entries_with(
+        self,
+        vocabulary: SymbolVocabulary,
+    ) -> impl Iterator<Item = (char, &'static CodeWord)> {
+        self.tables_with(vocabulary)
+            .flat_map(Table::iter)
+            .filter(move |(character, code_word)| {
+                self.lookup_with(vocabulary, *character)
+                    .is_some_and(|(found, _)| ptr::eq(found, *code_word))
+            })
+    }
+
+    // The tables of the alphabet, along with that of the given vocabulary.
+    fn tables_with(self, vocabulary: SymbolVocabulary) -> impl Iterator<Item = &'static Table> {
+        [vocabulary.table(), self.table()]
+            .into_iter()
+            .chain(self.base().map(Self::table))
+    }
+
+    // The code word table of the alphabet's own letters.
+    const fn table(self) -> &'static Table {
+        match self {
+            Self::Arabic => &ARABIC_ALPHABET,
+            Self::Bulgarian => &BULGARIAN_ALPHABET,
+            Self::Greek => &GREEK_ALPHABET,
+            Self::Hebrew => &HEBREW_ALPHABET,
+            Self::Icao1951Draft => &ICAO_1951_DRAFT_ALPHABET,
+            Self::Itu1927 => &ITU_1927_ALPHABET,
+            Self::Itu1938 => &ITU_1938_ALPHABET,
+            Self::Jan => &JAN_ALPHABET,
+            Self::Korean => &KOREAN_ALPHABET,
+            Self::Lapd => &LAPD_ALPHABET,
+            Self::Nato => &NATO_ALPHABET,
+            Self::Raf1924 => &RAF_1924_ALPHABET,
+            Self::Raf1942 => &RAF_1942_ALPHABET,
+            Self::RoyalNavy => &ROYAL_NAVY_ALPHABET,
+            Self::Russian => &RUSSIAN_ALPHABET,
+            Self::Ukrainian => &UKRAINIAN_ALPHABET,
+            Self::UsFinancial => &US_FINANCIAL_ALPHABET,
+            Self::UsTelephone => &US_TELEPHONE_ALPHABET,
+            Self::Wabun => &WABUN_ALPHABET,
+            Self::WesternUnion => &WESTERN_UNION_ALPHABET,
+        }
+    }
+
+    // The alphabet whose code words this one builds on, if any, like the NATO
+    // alphabet for the Latin letters in Greek text.
+    const fn base(self) -> Option<Self> {
+        match self {
+            Self::Greek => Some(Self::Nato),
+            _ => None,
+        }
+    }
 
-        extend_map(&mut map, &DEFAULT_DIGITS_AND_SYMBOLS);
+    /// Returns the language of the `SpellingAlphabet`'s code words, as an IETF
+    /// BCP 47 language tag.
+    #[must_use]
+    pub const fn language(&self) -> &'static str {
+        match self {
+            Self::Arabic => "ar",
+            Self::Bulgarian => "bg",
+            Self::Greek => "el",
+            Self::Hebrew => "he",
+            Self::Korean => "ko",
+            Self::Russian => "ru",
+            Self::Ukrainian => "uk",
+            Self::Wabun => "ja",
+            _ => "en",
+        }
+    }
+
+    /// Returns true if each letter's code word in the `SpellingAlphabet` starts
+    /// with the letter it spells, like "Alfa" for "a".
+    ///
+    /// This holds for the alphabets of Latin letters and for the Greek
+    /// alphabet. Letter names, like those of Hebrew or Korean, and alphabets
+    /// that spell some letters with names, like the Cyrillic signs, don't
+    /// follow this convention.
+    #[must_use]
+    pub const fn is_acrophonic(&self) -> bool {
+        !matches!(
+            self,
+            Self::Arabic
+                | Self::Bulgarian
+                | Self::Hebrew
+                | Self::Korean
+                | Self::Russian
+                | Self::Ukrainian
+                | Self::Wabun
+        )
+    }
+
+    const fn nonce_style(self) -> NonceStyle {
+        match self {
+            Self::Arabic => NonceStyle::AsIn("كما في"),
+            Self::Bulgarian => NonceStyle::AsIn("като в"),
+            Self::Greek => NonceStyle::AsIn("όπως στο"),
+            Self::Hebrew => NonceStyle::AsIn("כמו"),
+            Self::Korean => NonceStyle::AsIn("즉"),
+            Self::Russian => NonceStyle::AsIn("как в"),
+            Self::Ukrainian => NonceStyle::AsIn("як у"),
+            Self::Wabun => NonceStyle::No,
+            _ => NonceStyle::AsIn("as in"),
+        }
+    }
+
+    // The words that precede a letter to call out its case, in the alphabet's
+    // language, for encodings where case is significant.
+    const fn case_words(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Arabic => ("حرف كبير", "حرف صغير"),
+            Self::Bulgarian => ("Главна", "Малка"),
+            Self::Greek => ("Κεφαλαίο", "Πεζό"),
+            Self::Hebrew => ("אות גדולה", "אות קטנה"),
+            Self::Korean => ("대문자", "소문자"),
+            Self::Russian => ("Заглавная", "Строчная"),
+            Self::Ukrainian => ("Велика", "Мала"),
+            Self::Wabun => ("大文字", "小文字"),
+            _ => ("Uppercase", "Lowercase"),
+        }
+    }
+}
+
+impl SymbolVocabulary {
+    /// Returns the language of the `SymbolVocabulary`'s code words, as an IETF
+    /// BCP 47 language tag.
+    #[must_use]
+    pub const fn language(&self) -> &'static str {
+        match self {
+            Self::Arabic => "ar",
+            Self::Bulgarian => "bg",
+            Self::Dutch => "nl",
+            Self::French => "fr",
+            Self::German => "de",
+            Self::Greek => "el",
+            Self::Hebrew => "he",
+            Self::Italian => "it",
+            Self::Japanese => "ja",
+            Self::Korean => "ko",
+            Self::Russian => "ru",
+            Self::Spanish => "es",
+            Self::Ukrainian => "uk",
+            Self::English | Self::Formal | Self::Military | Self::Programmer => "en",
+        }
+    }
 
+    // The digit and symbol code word table of the vocabulary.
+    const fn table(self) -> &'static Table {
         match self {
-            Self::Jan => extend_map(&mut map, &JAN_ALPHABET),
-            Self::Lapd => extend_map(&mut map, &LAPD_ALPHABET),
-            Self::Nato => extend_map(&mut map, &NATO_ALPHABET),
-            Self::RoyalNavy => extend_map(&mut map, &ROYAL_NAVY_ALPHABET),
-            Self::UsFinancial => extend_map(&mut map, &US_FINANCIAL_ALPHABET),
-            Self::WesternUnion => extend_map(&mut map, &WESTERN_UNION_ALPHABET),
+            Self::Arabic => &ARABIC_DIGITS_AND_SYMBOLS,
+            Self::Bulgarian => &BULGARIAN_DIGITS_AND_SYMBOLS,
+            Self::Dutch => &DUTCH_DIGITS_AND_SYMBOLS,
+            Self::English => &DEFAULT_DIGITS_AND_SYMBOLS,
+            Self::Formal => &FORMAL_DIGITS_AND_SYMBOLS,
+            Self::French => &FRENCH_DIGITS_AND_SYMBOLS,
+            Self::German => &GERMAN_DIGITS_AND_SYMBOLS,
+            Self::Greek => &GREEK_DIGITS_AND_SYMBOLS,
+            Self::Hebrew => &HEBREW_DIGITS_AND_SYMBOLS,
+            Self::Italian => &ITALIAN_DIGITS_AND_SYMBOLS,
+            Self::Japanese => &JAPANESE_DIGITS_AND_SYMBOLS,
+            Self::Korean => &KOREAN_DIGITS_AND_SYMBOLS,
+            Self::Military => &MILITARY_DIGITS_AND_SYMBOLS,
+            Self::Programmer => &PROGRAMMER_DIGITS_AND_SYMBOLS,
+            Self::Russian => &RUSSIAN_DIGITS_AND_SYMBOLS,
+            Self::Spanish => &SPANISH_DIGITS_AND_SYMBOLS,
+            Self::Ukrainian => &UKRAINIAN_DIGITS_AND_SYMBOLS,
         }
+    }
+}
 
-        map
+impl OverrideCase {
+    // Creates the code word of an override with its capitalization normalized,
+    // keeping the words within it even when they're joined.
+    fn code_word(self, word: &str) -> CodeWord {
+        match self {
+            Self::Pascal => CodeWord::joined(word.to_case(Case::Title).split_whitespace()),
+            Self::Preserve => CodeWord::new(word.to_string()),
+            Self::Title => {
+                let mut result = String::with_capacity(word.len());
+                let mut word_start = true;
+                for c in word.chars() {
+                    if word_start {
+                        result.extend(c.to_uppercase());
+                    } else {
+                        result.push(c);
+                    }
+                    word_start = c.is_whitespace() || c == '-';
+                }
+                CodeWord::new(result)
+            }
+        }
     }
 }
@@ -0,0 +1,259 @@
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::UnknownChars;
+
+// International Telecommunication Union (ITU) Morse code, per ITU-R M.1677-1,
+// along with the common extensions for "!", "$", "&", ";", and "_".
+const MORSE_CODE: [(char, &str); 55] = [
+    ('a', ".-"),
+    ('b', "-..."),
+    ('c', "-.-."),
+    ('d', "-.."),
+    ('e', "."),
+    ('f', "..-."),
+    ('g', "--."),
+    ('h', "...."),
+    ('i', ".."),
+    ('j', ".---"),
+    ('k', "-.-"),
+    ('l', ".-.."),
+    ('m', "--"),
+    ('n', "-."),
+    ('o', "---"),
+    ('p', ".--."),
+    ('q', "--.-"),
+    ('r', ".-."),
+    ('s', "..."),
+    ('t', "-"),
+    ('u', "..-"),
+    ('v', "...-"),
+    ('w', ".--"),
+    ('x', "-..-"),
+    ('y', "-.--"),
+    ('z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('!', "-.-.--"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('&', ".-..."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('+', ".-.-."),
+    (',', "--..--"),
+    ('-', "-....-"),
+    ('.', ".-.-.-"),
+    ('/', "-..-."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('?', "..--.."),
+    ('@', ".--.-."),
+    ('\'', ".----."),
+    ('_', "..--.-"),
+    ('×', "-..-"),
+];
+
+/// A single element of Morse code output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum MorseElement {
+    /// A short mark.
+    Dit,
+    /// A long mark, three times the length of a dit.
+    Dah,
+    /// The gap between the characters of a word.
+    LetterGap,
+    /// The gap between words.
+    WordGap,
+    /// A character without Morse code, kept by the [`UnknownChars`] policy.
+    Unknown(char),
+}
+
+/// A Morse code encoder.
+///
+/// Letters, digits, and punctuation are encoded using International
+/// Telecommunication Union (ITU) Morse code. Procedural signs are written as
+/// their letters within angle brackets, like "<AR>" or "<SK>", and are sent
+/// as a single character without letter gaps.
+///
+/// # Examples
+///
+/// ```
+/// # use spellabet::MorseEncoder;
+/// let encoder = MorseEncoder::new();
+/// assert_eq!(encoder.encode("SOS"), "... --- ...");
+/// assert_eq!(encoder.encode("Hi all <AR>"), ".... .. / .- .-.. .-.. / .-.-.");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MorseEncoder {
+    /// The symbol for a short mark.
+    dit: String,
+    /// The symbol for a long mark.
+    dah: String,
+    /// The separator between the characters of a word.
+    letter_gap: String,
+    /// The separator between words.
+    word_gap: String,
+    /// How characters without Morse code are handled.
+    unknown_chars: UnknownChars,
+}
+
+impl MorseEncoder {
+    /// Creates and returns a new instance of `MorseEncoder`, which writes dits
+    /// as ".", dahs as "-", letter gaps as " ", and word gaps as " / ".
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            dit: ".".to_string(),
+            dah: "-".to_string(),
+            letter_gap: " ".to_string(),
+            word_gap: " / ".to_string(),
+            unknown_chars: UnknownChars::default(),
+        }
+    }
+
+    /// Configures the symbol written for a dit.
+    #[must_use]
+    pub fn dit(mut self, dit: &str) -> Self {
+        dit.clone_into(&mut self.dit);
+        self
+    }
+
+    /// Configures the symbol written for a dah.
+    #[must_use]
+    pub fn dah(mut self, dah: &str) -> Self {
+        dah.clone_into(&mut self.dah);
+        self
+    }
+
+    /// Configures the separator written between the characters of a word.
+    #[must_use]
+    pub fn letter_gap(mut self, letter_gap: &str) -> Self {
+        letter_gap.clone_into(&mut self.letter_gap);
+        self
+    }
+
+    /// Configures the separator written between words.
+    #[must_use]
+    pub fn word_gap(mut self, word_gap: &str) -> Self {
+        word_gap.clone_into(&mut self.word_gap);
+        self
+    }
+
+    /// Configures how characters without Morse code are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{MorseEncoder, UnknownChars};
+    /// let encoder = MorseEncoder::new();
+    /// assert_eq!(encoder.encode("a#b"), ".- # -...");
+    ///
+    /// let encoder = encoder.unknown_chars(&UnknownChars::Skip);
+    /// assert_eq!(encoder.encode("a#b"), ".- -...");
+    /// ```
+    #[must_use]
+    pub const fn unknown_chars(mut self, unknown_chars: &UnknownChars) -> Self {
+        self.unknown_chars = *unknown_chars;
+        self
+    }
+
+    /// Encodes the given text into a string of Morse code, using the
+    /// configured symbols.
+    #[must_use]
+    pub fn encode(&self, text: &str) -> String {
+        let mut result = String::new();
+        for element in self.elements(text) {
+            match element {
+                MorseElement::Dit => result.push_str(&self.dit),
+                MorseElement::Dah => result.push_str(&self.dah),
+                MorseElement::LetterGap => result.push_str(&self.letter_gap),
+                MorseElement::WordGap => result.push_str(&self.word_gap),
+                MorseElement::Unknown(character) => result.push(character),
+            }
+        }
+        result
+    }
+
+    /// Encodes the given text into a sequence of Morse code elements, like for
+    /// keying a transmitter or synthesizing audio.
+    ///
+    /// Runs of whitespace become a single word gap, and leading or trailing
+    /// whitespace is dropped.
+    #[must_use]
+    pub fn elements(&self, text: &str) -> Vec<MorseElement> {
+        let mut elements = Vec::new();
+        for word in text.split_whitespace() {
+            let start = elements.len();
+            let mut rest = word;
+            while let Some(character) = rest.chars().next() {
+                let codes;
+                (codes, rest) =
+                    prosign(rest).unwrap_or_else(|| rest.split_at(character.len_utf8()));
+
+                let mut symbols = String::new();
+                for c in codes.chars() {
+                    match morse_code(c) {
+                        Some(code) => symbols.push_str(code),
+                        None => symbols.clear(),
+                    }
+                }
+
+                if symbols.is_empty() && self.unknown_chars == UnknownChars::Skip {
+                    continue;
+                }
+                if elements.len() > start {
+                    elements.push(MorseElement::LetterGap);
+                }
+                if symbols.is_empty() {
+                    elements.push(MorseElement::Unknown(character));
+                } else {
+                    elements.extend(symbols.chars().map(|symbol| match symbol {
+                        '.' => MorseElement::Dit,
+                        _ => MorseElement::Dah,
+                    }));
+                }
+            }
+            if elements.len() > start && start > 0 {
+                elements.insert(start, MorseElement::WordGap);
+            }
+        }
+        elements
+    }
+}
+
+impl Default for MorseEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Splits a procedural sign, written like "<AR>", from the start of the text
+// when every one of its letters has Morse code. Returns its letters along with
+// the rest of the text.
+fn prosign(text: &str) -> Option<(&str, &str)> {
+    let (letters, rest) = text.strip_prefix('<')?.split_once('>')?;
+    let is_prosign = letters.chars().count() > 1
+        && letters
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() && morse_code(c).is_some());
+    is_prosign.then_some((letters, rest))
+}
+
+fn morse_code(character: char) -> Option<&'static str> {
+    let key = character.to_ascii_lowercase();
+    MORSE_CODE
+        .iter()
+        .find(|(c, _)| *c == key)
+        .map(|(_, code)| *code)
+}
@@ -0,0 +1,263 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::code_word::pronunciation_of;
+use crate::decompose::in_script_of;
+use crate::{NonceStyle, PhoneticConverter, Token, WordStyle};
+
+/// How uppercase letters are signaled in SSML output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Default)]
+pub enum UppercaseMarkup {
+    /// Wrap the code word in a strong `<emphasis>` element.
+    /// This is the default.
+    #[default]
+    Emphasis,
+    /// Wrap the code word in a `<prosody>` element that raises its pitch.
+    Prosody,
+}
+
+/// Options for rendering conversions as Speech Synthesis Markup Language
+/// (SSML).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct SsmlOptions {
+    /// The pause between characters in milliseconds.
+    char_break: u32,
+    /// The pause between words in milliseconds.
+    word_break: u32,
+    /// How uppercase letters are signaled.
+    uppercase: UppercaseMarkup,
+    /// Is set when letters are wrapped in their nonce form phrasing.
+    nonce_form: bool,
+}
+
+impl SsmlOptions {
+    /// Creates and returns a new instance of `SsmlOptions`, with a 250 ms
+    /// pause between characters and a 750 ms pause between words.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            char_break: 250,
+            word_break: 750,
+            uppercase: UppercaseMarkup::Emphasis,
+            nonce_form: false,
+        }
+    }
+
+    /// Configures the pause between characters in milliseconds.
+    #[must_use]
+    pub const fn char_break(mut self, millis: u32) -> Self {
+        self.char_break = millis;
+        self
+    }
+
+    /// Configures the pause between words in milliseconds.
+    #[must_use]
+    pub const fn word_break(mut self, millis: u32) -> Self {
+        self.word_break = millis;
+        self
+    }
+
+    /// Configures how uppercase letters are signaled.
+    #[must_use]
+    pub const fn uppercase(mut self, uppercase: &UppercaseMarkup) -> Self {
+        self.uppercase = *uppercase;
+        self
+    }
+
+    /// Configures whether letters are wrapped in their nonce form phrasing,
+    /// like "'A' as in ALFA" in the alphabet's language, with each phrase marked
+    /// as a sentence.
+    #[must_use]
+    pub const fn nonce_form(mut self, nonce_form: bool) -> Self {
+        self.nonce_form = nonce_form;
+        self
+    }
+}
+
+impl Default for SsmlOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PhoneticConverter {
+    /// Converts the given text into an SSML 1.1 document of code words, ready
+    /// to send to a speech engine.
+    ///
+    /// Code words are spoken as lowercase words, with compound code words like
+    /// "`DoubleQuote`" split apart. A pause separates each character, and
+    /// whitespace becomes a longer pause between words. Uppercase letters are
+    /// signaled with markup, and English code words whose standard
+    /// [`Pronunciation`](crate::Pronunciation) differs from their spelling,
+    /// like "Niner", are given its respelling as a spoken alias.
+    ///
+    /// The document is in the language of the spelling alphabet, and code
+    /// words from a symbol vocabulary or alphabet in another language are
+    /// marked with their own.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to convert into code words.
+    /// * `options` - The [`SsmlOptions`] to render with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spellabet::{PhoneticConverter, SpellingAlphabet, SsmlOptions};
+    /// let converter = PhoneticConverter::new(&SpellingAlphabet::default());
+    /// let ssml = converter.to_ssml("A9", &SsmlOptions::new());
+    /// assert_eq!(
+    ///     ssml,
+    ///     "<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" \
+    ///      xml:lang=\"en\"><emphasis level=\"strong\"><sub alias=\"al fah\">alfa</sub>\
+    ///      </emphasis><break time=\"250ms\"/><sub alias=\"nin er\">niner</sub></speak>"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_ssml(&self, text: &str, options: &SsmlOptions) -> String {
+        let mut result = format!(
+            "<speak version=\"1.1\" xmlns=\"http://www.w3.org/2001/10/synthesis\" \
+             xml:lang=\"{}\">",
+            self.alphabet.language()
+        );
+
+        let mut pause = None;
+        for token in self.styled_tokens(text, WordStyle::Spaced) {
+            if token.character().is_whitespace() {
+                pause = pause.map(|_| options.word_break);
+                continue;
+            }
+            if let Some(millis) = pause {
+                write!(result, "<break time=\"{millis}ms\"/>").unwrap();
+            }
+            self.write_token(&token, options, &mut result);
+            pause = Some(options.char_break);
+        }
+
+        result.push_str("</speak>");
+        result
+    }
+
+    fn write_token(&self, token: &Token, options: &SsmlOptions, result: &mut String) {
+        let character = token.character();
+        let mut words = token.code_words().iter().zip(token.languages());
+        let Some((code_word, language)) = words.next() else {
+            write_characters(character, result);
+            return;
+        };
+        let word = Word {
+            text: code_word,
+            language,
+            character,
+        };
+
+        let is_nonce = options.nonce_form && character.is_alphabetic();
+        let (base, marks) = token.decomposition();
+        let mut mark_words = String::new();
+        for (i, (mark_word, language)) in words.enumerate() {
+            match marks.get(i) {
+                Some(mark) if is_nonce && matches!(self.nonce_style, NonceStyle::No) => {
+                    mark_words.push_str(mark.particle());
+                }
+                _ => mark_words.push(' '),
+            }
+            let mark_word = Word {
+                text: mark_word,
+                language,
+                character,
+            };
+            self.write_word(&mark_word, options, &mut mark_words);
+        }
+
+        if is_nonce {
+            result.push_str("<s>");
+            match self.nonce_style {
+                NonceStyle::AsIn(as_in) => {
+                    write_characters(character, result);
+                    write!(result, " {as_in} ").unwrap();
+                    self.write_word(&word, options, result);
+                }
+                NonceStyle::No => {
+                    self.write_word(&word, options, result);
+                    result.push('の');
+                    let letter = base.map_or(character, |base| in_script_of(base, character));
+                    write_characters(letter, result);
+                }
+            }
+            result.push_str(&mark_words);
+            result.push_str("</s>");
+        } else {
+            self.write_word(&word, options, result);
+            result.push_str(&mark_words);
+        }
+    }
+
+    // Write a code word, signaling uppercase, its spoken alias, and its
+    // language when it differs from that of the document.
+    fn write_word(&self, word: &Word, options: &SsmlOptions, result: &mut String) {
+        let text = word.text.to_lowercase();
+        let alias = pronunciation_of(&text)
+            .filter(|_| word.language == "en")
+            .map(|pronunciation| pronunciation.respelling().to_lowercase())
+            .filter(|alias| *alias != text);
+        let mut spoken = alias.map_or_else(
+            || escape(&text),
+            |alias| format!("<sub alias=\"{}\">{}</sub>", escape(&alias), escape(&text)),
+        );
+        if word.language != self.alphabet.language() {
+            spoken = format!("<lang xml:lang=\"{}\">{spoken}</lang>", word.language);
+        }
+        write_cased(&spoken, word.character, options, result);
+    }
+}
+
+// A code word to write, along with its language and the character it spells.
+struct Word<'a> {
+    text: &'a str,
+    language: &'a str,
+    character: char,
+}
+
+// Write a spoken code word, signaling uppercase.
+fn write_cased(spoken: &str, character: char, options: &SsmlOptions, result: &mut String) {
+    if character.is_uppercase() {
+        match options.uppercase {
+            UppercaseMarkup::Emphasis => {
+                write!(result, "<emphasis level=\"strong\">{spoken}</emphasis>").unwrap();
+            }
+            UppercaseMarkup::Prosody => {
+                write!(result, "<prosody pitch=\"+15%\">{spoken}</prosody>").unwrap();
+            }
+        }
+    } else {
+        result.push_str(spoken);
+    }
+}
+
+// Write a character to be read as itself.
+fn write_characters(character: char, result: &mut String) {
+    let mut buf = [0; 4];
+    write!(
+        result,
+        "<say-as interpret-as=\"characters\">{}</say-as>",
+        escape(character.encode_utf8(&mut buf))
+    )
+    .unwrap();
+}
+
+// Escape the characters that are special in XML text and attributes.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
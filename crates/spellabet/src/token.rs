@@ -0,0 +1,83 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::decompose::Mark;
+use crate::{AmbiguousGlyph, Spelling};
+
+/// A character of converted text, along with the code words spelling it.
+///
+/// Most characters are spelled by a single code word. Accented letters may be
+/// followed by the code words of their marks, and syllable blocks are spelled
+/// by the code words of their component letters.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Token {
+    /// The character of text.
+    character: char,
+    /// The code words spelling the character, if it could be converted.
+    code_words: Vec<String>,
+    /// The language of each code word.
+    languages: Vec<&'static str>,
+    /// The base letter spelled by the first code word, for a decomposed
+    /// character, and the mark spelled by each code word after it.
+    base: Option<char>,
+    marks: Vec<Mark>,
+    /// The ambiguous glyph that the character is part of, if any.
+    glyph: Option<AmbiguousGlyph>,
+}
+
+impl Token {
+    pub(crate) fn new(character: char, spelling: Spelling, glyph: Option<AmbiguousGlyph>) -> Self {
+        Self {
+            character,
+            code_words: spelling.words.into_iter().map(Cow::into_owned).collect(),
+            languages: spelling.languages,
+            base: spelling.base,
+            marks: spelling.marks,
+            glyph,
+        }
+    }
+
+    /// Returns the character of text.
+    #[must_use]
+    pub const fn character(&self) -> char {
+        self.character
+    }
+
+    /// Returns the code words spelling the character, with the converter's
+    /// word style and the character's case applied. There are none when the
+    /// character couldn't be converted.
+    #[must_use]
+    pub fn code_words(&self) -> &[String] {
+        &self.code_words
+    }
+
+    /// Returns the language of each code word, as IETF BCP 47 language tags.
+    ///
+    /// Code words are in the language of the spelling alphabet or symbol
+    /// vocabulary they come from, like "en" for the NATO alphabet's "Alfa".
+    /// Overrides are taken to be in the language of the spelling alphabet.
+    #[must_use]
+    pub fn languages(&self) -> &[&'static str] {
+        &self.languages
+    }
+
+    // Returns the base letter and marks of a decomposed character.
+    pub(crate) fn decomposition(&self) -> (Option<char>, &[Mark]) {
+        (self.base, &self.marks)
+    }
+
+    /// Returns true if the character was converted into code words.
+    #[must_use]
+    pub const fn is_converted(&self) -> bool {
+        !self.code_words.is_empty()
+    }
+
+    /// Returns the ambiguous glyph that the character is part of, like the
+    /// "rn" that may be mistaken for an "m", if any. See
+    /// [`ConfusableGlyphs`](crate::ConfusableGlyphs).
+    #[must_use]
+    pub const fn ambiguous_glyph(&self) -> Option<&AmbiguousGlyph> {
+        self.glyph.as_ref()
+    }
+}
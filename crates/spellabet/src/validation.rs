@@ -0,0 +1,192 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::decompose::{decompose, is_bidi_control};
+use crate::{CodeWord, Map, PhoneticConverter, normalize_key};
+
+/// A problem found in the character mappings of a `PhoneticConverter`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// # use spellabet::{Finding, PhoneticConverter, SpellingAlphabet};
+///
+/// let mut overrides_map = HashMap::new();
+/// overrides_map.insert('b', "Alfa".to_string());
+///
+/// let converter =
+///     PhoneticConverter::new(&SpellingAlphabet::default()).with_overrides(overrides_map);
+/// let findings = converter.validate();
+/// assert_eq!(findings.len(), 2);
+/// assert_eq!(findings[0].to_string(), "'a' and 'b' share the code word \"Alfa\"");
+/// assert_eq!(
+///     findings[1].to_string(),
+///     "'b' has the code word \"Alfa\", which doesn't start with that letter"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Finding {
+    /// Two characters share one code word, ignoring case, so a listener can't
+    /// tell which was meant.
+    DuplicateCodeWord {
+        characters: (char, char),
+        code_word: CodeWord,
+    },
+    /// A character's code word is empty.
+    EmptyCodeWord { character: char },
+    /// A letter's code word doesn't start with that letter, in a spelling
+    /// alphabet that follows that convention.
+    InitialMismatch {
+        character: char,
+        code_word: CodeWord,
+    },
+    /// A letter a–z or digit 0–9 has no code word. Letters are only expected
+    /// when the mappings spell any of them.
+    MissingCharacter { character: char },
+    /// A character's code word contains a non-printable character, like a
+    /// control character or an invisible formatting character.
+    NonPrintable {
+        character: char,
+        code_word: CodeWord,
+        found: char,
+    },
+    /// A character's code word starts or ends with whitespace.
+    PaddedCodeWord {
+        character: char,
+        code_word: CodeWord,
+    },
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateCodeWord {
+                characters: (first, second),
+                code_word,
+            } => write!(
+                f,
+                "{first:?} and {second:?} share the code word {:?}",
+                code_word.as_str()
+            ),
+            Self::EmptyCodeWord { character } => write!(f, "{character:?} has an empty code word"),
+            Self::InitialMismatch {
+                character,
+                code_word,
+            } => write!(
+                f,
+                "{character:?} has the code word {:?}, which doesn't start with that letter",
+                code_word.as_str()
+            ),
+            Self::MissingCharacter { character } => write!(f, "{character:?} has no code word"),
+            Self::NonPrintable {
+                character,
+                code_word,
+                found,
+            } => write!(
+                f,
+                "{character:?} has the code word {:?}, which contains the non-printable \
+                 character U+{:04X}",
+                code_word.as_str(),
+                u32::from(*found)
+            ),
+            Self::PaddedCodeWord {
+                character,
+                code_word,
+            } => write!(
+                f,
+                "{character:?} has the code word {:?}, which starts or ends with whitespace",
+                code_word.as_str()
+            ),
+        }
+    }
+}
+
+impl PhoneticConverter {
+    /// Checks the current character mappings of the `PhoneticConverter`
+    /// instance for structural problems, including those of any overrides.
+    ///
+    /// Findings are returned in the order of their characters, sorted by
+    /// letters, then digits, then symbols, followed by any missing characters.
+    /// The built-in spelling alphabets have none, apart from a few quirks like
+    /// the 1951 ICAO draft's "Equis" for "x", and the Western Union "Zero" for
+    /// both "z" and "0".
+    ///
+    /// See [`Finding`] for the problems that are checked.
+    #[must_use]
+    pub fn validate(&self) -> Vec<Finding> {
+        let mappings = self.sorted_mappings();
+        let mut findings = Vec::new();
+
+        let mut seen: Map<String, char> = Map::new();
+        for (character, code_word) in &mappings {
+            let (character, text) = (*character, code_word.as_str());
+
+            if text.is_empty() {
+                findings.push(Finding::EmptyCodeWord { character });
+                continue;
+            }
+
+            if let Some(first) = seen.get(&text.to_lowercase()) {
+                findings.push(Finding::DuplicateCodeWord {
+                    characters: (*first, character),
+                    code_word: code_word.clone(),
+                });
+            } else {
+                seen.insert(text.to_lowercase(), character);
+            }
+
+            if self.alphabet.is_acrophonic()
+                && character.is_alphabetic()
+                && text.trim_start().chars().next().map(base_letter) != Some(base_letter(character))
+            {
+                findings.push(Finding::InitialMismatch {
+                    character,
+                    code_word: code_word.clone(),
+                });
+            }
+
+            if text.trim() != text {
+                findings.push(Finding::PaddedCodeWord {
+                    character,
+                    code_word: code_word.clone(),
+                });
+            }
+
+            if let Some(found) = text.chars().find(|c| !is_printable(*c)) {
+                findings.push(Finding::NonPrintable {
+                    character,
+                    code_word: code_word.clone(),
+                    found,
+                });
+            }
+        }
+
+        let spells_latin = ('a'..='z').any(|c| self.lookup(c).is_some());
+        let expected = ('a'..='z').filter(|_| spells_latin).chain('0'..='9');
+        for character in expected {
+            if self.lookup(character).is_none() {
+                findings.push(Finding::MissingCharacter { character });
+            }
+        }
+
+        findings
+    }
+}
+
+// Reduce a letter to its lowercase base letter, without any accents.
+fn base_letter(character: char) -> char {
+    let key = normalize_key(character);
+    decompose(key)
+        .filter(|decomposition| decomposition.is_accent)
+        .map_or(key, |decomposition| decomposition.base)
+}
+
+// Check that a character inside a code word will be visible when printed.
+// Spaces are allowed between the words of a code word.
+fn is_printable(character: char) -> bool {
+    !(character.is_control()
+        || is_bidi_control(character)
+        || matches!(character, '\u{200b}'..='\u{200d}' | '\u{2060}' | '\u{feff}'))
+}
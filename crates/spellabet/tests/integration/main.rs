@@ -4,7 +4,11 @@
 use std::collections::HashMap;
 
 use insta::assert_snapshot;
-use spellabet::{PhoneticConverter, SpellingAlphabet};
+use spellabet::{
+    Change, CharacterClass, Checksum, ChecksumError, CodeWord, ConfusableGlyphs, Encoding,
+    EncodingError, Era, Finding, MorseElement, MorseEncoder, OverrideCase, PhoneticConverter,
+    SpellingAlphabet, SsmlOptions, SymbolVocabulary, UnknownChars, UppercaseMarkup, WordStyle,
+};
 
 fn init_converter() -> PhoneticConverter {
     let alphabet = SpellingAlphabet::default();
@@ -60,6 +64,9 @@ fn test_empty_string() {
 fn test_unknown_characters() {
     let converter = init_converter();
     assert_snapshot!(converter.convert("aΦb💩c"), @"alfa Φ bravo 💩 charlie");
+
+    // Check that syllables aren't decomposed without jamo code words
+    assert_snapshot!(converter.convert("한"), @"한");
 }
 
 #[test]
@@ -77,6 +84,17 @@ fn test_mappings() {
 
     // Check that the size of mappings matches the expect size
     assert_eq!(mappings.len(), 69);
+
+    // Check that the mappings are kept, and follow later settings
+    assert!(std::ptr::eq(mappings, converter.mappings()));
+    let converter = converter
+        .symbol_vocabulary(&SymbolVocabulary::German)
+        .with_overrides([('a', "Anton".to_string())]);
+    assert_eq!(converter.mappings()[&'a'], "Anton");
+    assert_eq!(converter.mappings()[&'9'], "Neun");
+    for (character, code_word) in converter.code_words() {
+        assert_eq!(converter.mappings()[&character], code_word.as_str());
+    }
 }
 
 #[test]
@@ -163,6 +181,34 @@ fn test_with_overrides() {
     assert_snapshot!(converter.convert("abc"), @"able baker charlie");
 }
 
+#[test]
+fn test_override_case() {
+    let mut overrides_map: HashMap<char, String> = HashMap::new();
+    overrides_map.insert('n', "new york".to_string());
+    overrides_map.insert('o', "O'Brien".to_string());
+    overrides_map.insert('x', "x-ray".to_string());
+
+    let converter = init_converter().with_overrides(overrides_map);
+    let mappings = converter.mappings();
+    assert_snapshot!(mappings[&'n'], @"NewYork");
+    assert_snapshot!(mappings[&'x'], @"XRay");
+
+    let converter = converter.override_case(&OverrideCase::Preserve);
+    let mappings = converter.mappings();
+    assert_snapshot!(mappings[&'n'], @"new york");
+    assert_snapshot!(mappings[&'o'], @"O'Brien");
+    assert_snapshot!(mappings[&'x'], @"x-ray");
+
+    let converter = converter.override_case(&OverrideCase::Title);
+    let mappings = converter.mappings();
+    assert_snapshot!(mappings[&'n'], @"New York");
+    assert_snapshot!(mappings[&'o'], @"O'Brien");
+    assert_snapshot!(mappings[&'x'], @"X-Ray");
+
+    // Check that case conversion still applies on top
+    assert_snapshot!(converter.convert("Xo"), @"X-RAY o'brien");
+}
+
 #[test]
 fn test_lowercase_key_in_overrides() {
     let mut converter = init_converter();
@@ -343,6 +389,170 @@ fn test_dump_alphabet_verbose() {
     "###);
 }
 
+#[test]
+fn test_arabic_alphabet() {
+    let alphabet = SpellingAlphabet::Arabic;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(converter.convert("سلام"), @"سين لام ألف ميم");
+    assert_snapshot!(converter.convert("مدرسة"), @"ميم دال راء سين تاء مربوطة");
+
+    // Check that positional forms are spelled as their base letters
+    assert_snapshot!(converter.convert("\u{feb3}\u{fee0}\u{fe8e}\u{fee1}"), @"سين لام ألف ميم");
+
+    // Check that lam-alef ligatures are spelled as both letters
+    assert_snapshot!(converter.convert("\u{fefb}"), @"لام ألف");
+
+    // Check that harakat are spelled as part of their letters, rather than
+    // kept as unknown characters
+    assert_snapshot!(converter.convert("كَتَبَ"), @"كاف تاء باء");
+    assert_snapshot!(converter.convert("مُحَمَّدٌ"), @"ميم حاء ميم دال");
+    assert_eq!(converter.tokens("كَتَبَ").len(), 3);
+}
+
+#[test]
+fn test_bidi_control_characters() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Hebrew);
+
+    // Check that bidi controls are dropped, without extra separators
+    assert_snapshot!(converter.convert("\u{202b}שלום\u{202c} 1\u{200f}"), @"שין למד וו מם רווח אחת");
+
+    // Check that bidi controls can be named using overrides
+    let mut overrides_map: HashMap<char, String> = HashMap::new();
+    overrides_map.insert('\u{200f}', "RightToLeftMark".to_string());
+    let converter = converter.with_overrides(overrides_map);
+    assert_snapshot!(converter.convert("א\u{200f}"), @"אלף RightToLeftMark");
+}
+
+#[test]
+fn test_bulgarian_alphabet() {
+    let alphabet = SpellingAlphabet::Bulgarian;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("абв123юя"),
+        @"антон борис васил Едно Две Три юмрук ямбол"
+    );
+}
+
+#[test]
+fn test_greek_alphabet() {
+    let alphabet = SpellingAlphabet::Greek;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("αβγ123ψω"),
+        @"αστήρ βύρων γαλή Ένα Δύο Τρία ψυχή ωμέγα"
+    );
+
+    // Check that Greek case is preserved
+    assert_snapshot!(converter.convert("Δδ"), @"ΔΌΞΑ δόξα");
+
+    // Check that Latin letters are still spelled
+    assert_snapshot!(converter.convert("ΑA"), @"ΑΣΤΉΡ ALFA");
+}
+
+#[test]
+fn test_greek_final_sigma() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Greek);
+    assert_snapshot!(converter.convert("Σσς"), @"ΣΩΤΉΡ σωτήρ σωτήρ");
+
+    // Check that overrides for the final sigma apply to its base letter
+    let mut overrides_map: HashMap<char, String> = HashMap::new();
+    overrides_map.insert('ς', "Σίγμα".to_string());
+    let converter = converter.with_overrides(overrides_map);
+    assert_snapshot!(converter.convert("Σσς"), @"ΣΊΓΜΑ σίγμα σίγμα");
+}
+
+#[test]
+fn test_greek_accented_vowels() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Greek);
+    assert_snapshot!(converter.convert("άΈϊΰ"), @"αστήρ ΕΡΜΉΣ ιδέα ύμνος");
+
+    let converter = converter.mark_accents(true);
+    assert_snapshot!(
+        converter.convert("άΈϊΰ"),
+        @"αστήρ τόνος ΕΡΜΉΣ ΤΌΝΟΣ ιδέα διαλυτικά ύμνος διαλυτικά τόνος"
+    );
+}
+
+#[test]
+fn test_greek_decomposed_accents() {
+    // Text in Normalization Form D spells like precomposed text
+    let nfd = "\u{3b1}\u{301}\u{395}\u{301}\u{3b9}\u{308}\u{3c5}\u{308}\u{301}";
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Greek);
+    assert_eq!(converter.convert(nfd), converter.convert("άΈϊΰ"));
+    assert_eq!(converter.tokens(nfd).len(), 4);
+
+    let converter = converter.mark_accents(true);
+    assert_snapshot!(
+        converter.convert(nfd),
+        @"αστήρ τόνος ΕΡΜΉΣ ΤΌΝΟΣ ιδέα διαλυτικά ύμνος διαλυτικά τόνος"
+    );
+    assert_snapshot!(converter.convert("\u{3c5}\u{344}"), @"ύμνος διαλυτικά τόνος");
+}
+
+#[test]
+fn test_mark_accents_without_mark_words() {
+    // Alphabets without mark code words only spell the base letter
+    let mut overrides_map: HashMap<char, String> = HashMap::new();
+    overrides_map.insert('α', "Alpha".to_string());
+    let converter = init_converter()
+        .with_overrides(overrides_map)
+        .mark_accents(true);
+    assert_snapshot!(converter.convert("ά"), @"alpha");
+}
+
+#[test]
+fn test_hebrew_alphabet() {
+    let alphabet = SpellingAlphabet::Hebrew;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(converter.convert("אבג123"), @"אלף בית גימל אחת שתיים שלוש");
+
+    // Check that final forms are spelled as their base letters
+    assert_snapshot!(converter.convert("ךםןףץ"), @"כף מם נון פא צדי");
+    assert_snapshot!(converter.convert("שלום"), @"שין למד וו מם");
+
+    // Check that niqqud are spelled as part of their letters
+    assert_snapshot!(converter.convert("שָׁלוֹם"), @"שין למד וו מם");
+    assert_snapshot!(converter.convert("בְּרֵאשִׁית"), @"בית ריש אלף שין יוד תו");
+    assert_eq!(converter.tokens("שָׁלוֹם").len(), 4);
+}
+
+#[test]
+fn test_icao_1951_draft_alphabet() {
+    let alphabet = SpellingAlphabet::Icao1951Draft;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("abc123xyz"),
+        @"ana brazil coco One Two Three equis yolanda zeta"
+    );
+}
+
+#[test]
+fn test_itu_1927_alphabet() {
+    let alphabet = SpellingAlphabet::Itu1927;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("abc123xyz"),
+        @"amsterdam baltimore canada One Two Three xantippe yokohama zululand"
+    );
+}
+
+#[test]
+fn test_itu_1938_alphabet() {
+    let alphabet = SpellingAlphabet::Itu1938;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("abc123xyz"),
+        @"amsterdam baltimore casablanca One Two Three xanthippe yokohama zurich"
+    );
+}
+
 #[test]
 fn test_jan_alphabet() {
     let alphabet = SpellingAlphabet::Jan;
@@ -354,6 +564,30 @@ fn test_jan_alphabet() {
     );
 }
 
+#[test]
+fn test_korean_alphabet() {
+    let alphabet = SpellingAlphabet::Korean;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    // Check that syllable blocks are spelled as grouped jamo
+    assert_snapshot!(converter.convert("한"), @"(히읗 아 니은)");
+    assert_snapshot!(converter.convert("한글 1"), @"(히읗 아 니은) (기역 으 리을) 공백 하나");
+    assert_snapshot!(converter.convert("아이"), @"(이응 아) (이응 이)");
+    assert_snapshot!(converter.convert("닭"), @"(디귿 아 리을기역)");
+
+    // Check that standalone jamo are spelled directly
+    assert_snapshot!(converter.convert("ㅎㅏㄴ"), @"히읗 아 니은");
+}
+
+#[test]
+fn test_korean_nonce_form() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Korean).nonce_form(true);
+    assert_snapshot!(
+        converter.convert("한글"),
+        @"'한' 즉 히읗 아 니은, '글' 즉 기역 으 리을"
+    );
+}
+
 #[test]
 fn test_lapd_alphabet() {
     let alphabet = SpellingAlphabet::Lapd;
@@ -385,6 +619,28 @@ fn test_nato_alphabet() {
     assert_snapshot!(converter.convert("9"), @"Niner");
 }
 
+#[test]
+fn test_raf_1924_alphabet() {
+    let alphabet = SpellingAlphabet::Raf1924;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("abc123xyz"),
+        @"ace beer charlie One Two Three x-ray yorker zebra"
+    );
+}
+
+#[test]
+fn test_raf_1942_alphabet() {
+    let alphabet = SpellingAlphabet::Raf1942;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("abc123xyz"),
+        @"apple beer charlie One Two Three x-ray yoke zebra"
+    );
+}
+
 #[test]
 fn test_royal_navy_alphabet() {
     let alphabet = SpellingAlphabet::RoyalNavy;
@@ -396,6 +652,40 @@ fn test_royal_navy_alphabet() {
     );
 }
 
+#[test]
+fn test_russian_alphabet() {
+    let alphabet = SpellingAlphabet::Russian;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("абв123юя"),
+        @"анна борис василий Один Два Три юрий яков"
+    );
+
+    // Check that Cyrillic case is preserved
+    assert_snapshot!(converter.convert("Жж"), @"ЖЕНЯ женя");
+    assert_snapshot!(converter.convert("Йй"), @"ИВАН КРАТКИЙ иван краткий");
+
+    // Check that multi-word letter names keep their words
+    let mappings = converter.code_words();
+    assert_eq!(mappings[&'ъ'].words(), ["Твёрдый", "знак"]);
+    assert_eq!(mappings[&'ь'].render(WordStyle::Kebab), "мягкий-знак");
+}
+
+#[test]
+fn test_ukrainian_alphabet() {
+    let alphabet = SpellingAlphabet::Ukrainian;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("абв123юя"),
+        @"антон богдан василь Один Два Три юрій яків"
+    );
+
+    // Check letters unique to the Ukrainian alphabet
+    assert_snapshot!(converter.convert("Ґґ Її Єє"), @"ҐУДЗИК ґудзик Пробіл ЇЖАК їжак Пробіл ЄВГЕН євген");
+}
+
 #[test]
 fn test_us_financial_alphabet() {
     let alphabet = SpellingAlphabet::UsFinancial;
@@ -407,6 +697,49 @@ fn test_us_financial_alphabet() {
     );
 }
 
+#[test]
+fn test_us_telephone_alphabet() {
+    let alphabet = SpellingAlphabet::UsTelephone;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(
+        converter.convert("abc123xyz"),
+        @"alice bertha charles One Two Three x-ray young zero"
+    );
+}
+
+#[test]
+fn test_wabun_alphabet() {
+    let alphabet = SpellingAlphabet::Wabun;
+    let converter = PhoneticConverter::new(&alphabet);
+
+    assert_snapshot!(converter.convert("あいう123"), @"朝日 いろは 上野 イチ ニ サン");
+
+    // Check that hiragana and katakana share code words
+    assert_snapshot!(converter.convert("トウキョウ"), @"東京 上野 切手 吉野 小文字 上野");
+    assert_snapshot!(converter.convert("とうきょう"), @"東京 上野 切手 吉野 小文字 上野");
+}
+
+#[test]
+fn test_wabun_modifiers() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Wabun);
+    assert_snapshot!(converter.convert("が"), @"為替 濁点");
+    assert_snapshot!(converter.convert("パ"), @"はがき 半濁点");
+    assert_snapshot!(converter.convert("っ"), @"つるかめ 小文字");
+    assert_snapshot!(converter.convert("ビール"), @"飛行機 濁点 長音 留守居");
+}
+
+#[test]
+fn test_wabun_nonce_form() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Wabun).nonce_form(true);
+    assert_snapshot!(converter.convert("アサヒ"), @"朝日のア, 桜のサ, 飛行機のヒ");
+
+    // Check that modified kana name their base kana, followed by the marks
+    assert_snapshot!(converter.convert("ガ1"), @"為替のカに濁点, イチ");
+    assert_snapshot!(converter.convert("がぱ"), @"為替のかに濁点, はがきのはに半濁点");
+    assert_snapshot!(converter.convert("キャ"), @"切手のキ, 大和のヤの小文字");
+}
+
 #[test]
 fn test_western_union_alphabet() {
     let alphabet = SpellingAlphabet::WesternUnion;
@@ -417,3 +750,782 @@ fn test_western_union_alphabet() {
         @"adams boston chicago One Two Three x-ray young zero"
     );
 }
+
+#[test]
+fn test_alphabet_eras() {
+    let historical: Vec<_> = SpellingAlphabet::ALL
+        .iter()
+        .filter(|alphabet| alphabet.era() == Era::Historical)
+        .map(|alphabet| format!("{alphabet:?} {}", alphabet.year().unwrap()))
+        .collect();
+
+    assert_snapshot!(historical.join("\n"), @r"
+    Icao1951Draft 1951
+    Itu1927 1927
+    Itu1938 1938
+    Jan 1941
+    Raf1924 1924
+    Raf1942 1942
+    RoyalNavy 1914
+    UsTelephone 1920
+    WesternUnion 1912
+    ");
+
+    assert_eq!(SpellingAlphabet::Nato.era(), Era::Current);
+    assert_eq!(SpellingAlphabet::Nato.year(), Some(1956));
+    assert_snapshot!(SpellingAlphabet::Raf1924.source(), @"Royal Air Force");
+}
+
+#[test]
+fn test_symbol_vocabularies() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato)
+        .symbol_vocabulary(&SymbolVocabulary::German);
+    assert_snapshot!(converter.convert("a9!"), @"alfa Neun Ausrufezeichen");
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato)
+        .symbol_vocabulary(&SymbolVocabulary::Spanish);
+    assert_snapshot!(converter.convert("1@"), @"Uno Arroba");
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato)
+        .symbol_vocabulary(&SymbolVocabulary::English);
+    assert_snapshot!(converter.convert("9"), @"Niner");
+
+    assert_eq!(
+        SpellingAlphabet::Nato.default_vocabulary(),
+        SymbolVocabulary::English
+    );
+}
+
+#[test]
+fn test_default_symbol_vocabularies() {
+    for alphabet in SpellingAlphabet::ALL {
+        let vocabulary = alphabet.default_vocabulary();
+        assert_eq!(vocabulary.language(), alphabet.language(), "{alphabet:?}");
+    }
+
+    // digits are spelled in the alphabet's own language
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Russian);
+    assert_snapshot!(converter.convert("а 90"), @"анна Пробел Девять Ноль");
+
+    // including for alphabets that build on another
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Greek);
+    assert_snapshot!(converter.convert("a9"), @"alfa Εννέα");
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Greek)
+        .symbol_vocabulary(&SymbolVocabulary::English);
+    assert_snapshot!(converter.convert("9"), @"Nine");
+}
+
+#[test]
+fn test_alternative_symbol_vocabularies() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato)
+        .symbol_vocabulary(&SymbolVocabulary::Programmer);
+    assert_snapshot!(converter.convert("#!/*`"), @"Hash Bang Slash Splat Backtick");
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato)
+        .symbol_vocabulary(&SymbolVocabulary::Formal);
+    assert_snapshot!(converter.convert("!#/"), @"ExclamationMark NumberSign Solidus");
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Lapd)
+        .symbol_vocabulary(&SymbolVocabulary::Military);
+    assert_snapshot!(converter.convert("a/3."), @"adam Stroke Tree Stop");
+}
+
+#[test]
+fn test_symbol_vocabulary_keeps_overrides() {
+    let mut overrides = HashMap::new();
+    overrides.insert('1', "Ein".to_string());
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato)
+        .with_overrides(overrides)
+        .symbol_vocabulary(&SymbolVocabulary::Dutch);
+    assert_snapshot!(converter.convert("12"), @"Ein Twee");
+}
+
+#[test]
+fn test_code_word_structure() {
+    assert_eq!(CodeWord::new("DoubleQuote").words(), ["DoubleQuote"]);
+    assert_eq!(
+        CodeWord::joined(["Double", "Quote"]).words(),
+        ["Double", "Quote"]
+    );
+    assert_eq!(CodeWord::new("X-ray").words(), ["X-ray"]);
+    assert_eq!(CodeWord::new("لام ألف").words(), ["لام", "ألف"]);
+
+    // the word boundaries of the built-in code words aren't guessed
+    let french = init_converter().symbol_vocabulary(&SymbolVocabulary::French);
+    let mappings = french.code_words();
+    assert_eq!(mappings[&'!'].words(), ["Point", "d'exclamation"]);
+    assert_eq!(mappings[&'<'].words(), ["Inférieur", "à"]);
+    assert_eq!(mappings[&'<'].render(WordStyle::Kebab), "inférieur-à");
+
+    let overrides = init_converter().with_overrides([('n', "new york".to_string())]);
+    assert_eq!(overrides.code_words()[&'n'].words(), ["New", "York"]);
+
+    // the words of the built-in code words are stored along with them
+    let mappings = init_converter().code_words();
+    let other_mappings = init_converter().code_words();
+    assert_eq!(mappings[&'"'].words(), ["Double", "Quote"]);
+    assert!(std::ptr::eq(
+        mappings[&'"'].words(),
+        other_mappings[&'"'].words()
+    ));
+}
+
+#[test]
+fn test_code_word_cases() {
+    let code_word = CodeWord::new("X-ray");
+    assert_eq!(code_word.lowercase(), "x-ray");
+    assert_eq!(code_word.uppercase(), "X-RAY");
+
+    let code_word = CodeWord::from(String::from("Ἄλφα"));
+    assert_eq!(code_word.lowercase(), "ἄλφα");
+    assert_eq!(code_word.uppercase(), "ἌΛΦΑ");
+}
+
+#[test]
+fn test_word_styles() {
+    let converter = init_converter();
+    assert_snapshot!(converter.convert("A\"(;"), @"ALFA DoubleQuote LeftParens SemiColon");
+
+    let converter = init_converter().word_style(&WordStyle::Spaced);
+    assert_snapshot!(converter.convert("A\"(;"), @"ALFA double quote left parens semi colon");
+
+    let converter = init_converter().word_style(&WordStyle::Kebab);
+    assert_snapshot!(converter.convert("A\"(;"), @"ALFA double-quote left-parens semi-colon");
+
+    let converter = init_converter().word_style(&WordStyle::ScreamingSnake);
+    assert_snapshot!(converter.convert("A\"(;"), @"ALFA DOUBLE_QUOTE LEFT_PARENS SEMI_COLON");
+}
+
+#[test]
+fn test_dump_alphabet_word_style() {
+    let converter = init_converter().word_style(&WordStyle::Kebab);
+    let mut buf = Vec::new();
+    converter.dump_alphabet(&mut buf, true).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    let dumped: Vec<_> = output
+        .lines()
+        .filter(|line| line.starts_with(['"', '(', ';']))
+        .collect();
+    assert_snapshot!(dumped.join("\n"), @r#"
+    " -> double-quote
+    ( -> left-parens
+    ; -> semi-colon
+    "#);
+}
+
+#[test]
+fn test_unknown_chars() {
+    let converter = init_converter();
+    assert_snapshot!(converter.convert("a€ b"), @"alfa € Space bravo");
+
+    let converter = converter.unknown_chars(&UnknownChars::Skip);
+    assert_snapshot!(converter.convert("€a€ b€"), @"alfa Space bravo");
+
+    let converter = converter.nonce_form(true);
+    assert_snapshot!(converter.convert("a€b"), @"'a' as in alfa, 'b' as in bravo");
+}
+
+#[test]
+fn test_morse() {
+    let encoder = MorseEncoder::new();
+    assert_snapshot!(encoder.encode("SOS"), @"... --- ...");
+    assert_snapshot!(encoder.encode("  Call 911?  "), @"-.-. .- .-.. .-.. / ----. .---- .---- ..--..");
+    assert_snapshot!(encoder.encode("73 <SK>"), @"--... ...-- / ...-.-");
+    assert_snapshot!(encoder.encode("<XY"), @"< -..- -.--");
+}
+
+#[test]
+fn test_morse_symbols() {
+    let encoder = MorseEncoder::new()
+        .dit("·")
+        .dah("−")
+        .letter_gap("   ")
+        .word_gap("       ");
+    assert_snapshot!(encoder.encode("hi yo"), @"····   ··       −·−−   −−−");
+
+    let encoder = encoder.unknown_chars(&UnknownChars::Skip);
+    assert_snapshot!(encoder.encode("€ a€ €"), @"·−");
+}
+
+#[test]
+fn test_morse_elements() {
+    let encoder = MorseEncoder::new();
+    assert_eq!(
+        encoder.elements("e t"),
+        [MorseElement::Dit, MorseElement::WordGap, MorseElement::Dah]
+    );
+    assert_eq!(
+        encoder.elements("e€"),
+        [
+            MorseElement::Dit,
+            MorseElement::LetterGap,
+            MorseElement::Unknown('€')
+        ]
+    );
+}
+
+#[test]
+fn test_tokens() {
+    let converter = init_converter();
+    let tokens = converter.tokens("Ab 9€");
+    let spelled: Vec<_> = tokens
+        .iter()
+        .map(|token| format!("{} {:?}", token.character(), token.code_words()))
+        .collect();
+    assert_snapshot!(spelled.join("\n"), @r#"
+    A ["ALFA"]
+    b ["bravo"]
+      ["Space"]
+    9 ["Niner"]
+    € []
+    "#);
+    assert!(!tokens[4].is_converted());
+
+    let converter = converter.unknown_chars(&UnknownChars::Skip);
+    assert_eq!(converter.tokens("a€").len(), 1);
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Greek).mark_accents(true);
+    assert_eq!(converter.tokens("ά")[0].code_words(), ["αστήρ", "τόνος"]);
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Korean);
+    assert_eq!(
+        converter.tokens("한")[0].code_words(),
+        ["히읗", "아", "니은"]
+    );
+}
+
+#[test]
+fn test_ssml() {
+    let converter = init_converter();
+    let options = SsmlOptions::new().char_break(100).word_break(500);
+    assert_snapshot!(
+        converter.to_ssml("B4 \"&€", &options),
+        @r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="en"><emphasis level="strong"><sub alias="brah voh">bravo</sub></emphasis><break time="100ms"/><sub alias="fow er">fower</sub><break time="500ms"/>double quote<break time="100ms"/>ampersand<break time="100ms"/><say-as interpret-as="characters">€</say-as></speak>"#
+    );
+}
+
+#[test]
+fn test_ssml_nonce_form() {
+    let converter = init_converter();
+    let options = SsmlOptions::new()
+        .uppercase(&UppercaseMarkup::Prosody)
+        .nonce_form(true);
+    assert_snapshot!(
+        converter.to_ssml("Ja", &options),
+        @r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="en"><s><say-as interpret-as="characters">J</say-as> as in <prosody pitch="+15%"><sub alias="jew lee ett">juliett</sub></prosody></s><break time="250ms"/><s><say-as interpret-as="characters">a</say-as> as in <sub alias="al fah">alfa</sub></s></speak>"#
+    );
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Wabun);
+    assert_snapshot!(
+        converter.to_ssml("ア", &options),
+        @r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="ja"><s>朝日の<say-as interpret-as="characters">ア</say-as></s></speak>"#
+    );
+    assert_snapshot!(
+        converter.to_ssml("ガ", &options),
+        @r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="ja"><s>為替の<say-as interpret-as="characters">カ</say-as>に濁点</s></speak>"#
+    );
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Russian);
+    assert_snapshot!(
+        converter.to_ssml("Ж", &options),
+        @r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="ru"><s><say-as interpret-as="characters">Ж</say-as> как в <prosody pitch="+15%">женя</prosody></s></speak>"#
+    );
+}
+
+#[test]
+fn test_ssml_languages() {
+    let options = SsmlOptions::new();
+
+    // Latin letters of the Greek alphabet come from the NATO alphabet
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Greek);
+    assert_snapshot!(converter.to_ssml("aα9", &options), @r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="el"><lang xml:lang="en"><sub alias="al fah">alfa</sub></lang><break time="250ms"/>αστήρ<break time="250ms"/>εννέα</speak>"#);
+    assert_eq!(converter.tokens("a")[0].languages(), ["en"]);
+
+    // code words follow the vocabulary they come from, and overrides follow
+    // the alphabet
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Russian)
+        .symbol_vocabulary(&SymbolVocabulary::English)
+        .with_overrides([('б', "Бык".to_string())]);
+    assert_snapshot!(converter.to_ssml("аб1", &options), @r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="ru">анна<break time="250ms"/>бык<break time="250ms"/><lang xml:lang="en"><sub alias="wun">one</sub></lang></speak>"#);
+
+    // pronunciations only apply to English code words
+    let converter = init_converter().symbol_vocabulary(&SymbolVocabulary::French);
+    assert_snapshot!(converter.to_ssml("6", &options), @r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="en"><lang xml:lang="fr">six</lang></speak>"#);
+}
+
+#[test]
+fn test_pronunciations() {
+    let pronunciation = CodeWord::new("Quebec").pronunciation().unwrap();
+    assert_eq!(pronunciation.respelling(), "KEH BECK");
+    assert_eq!(pronunciation.ipa(), "keˈbek");
+    assert!(CodeWord::new("NINER").pronunciation().is_some());
+    assert!(CodeWord::new("Apple").pronunciation().is_none());
+}
+
+#[test]
+fn test_respelling() {
+    let converter = init_converter().respell(true);
+    assert_snapshot!(converter.convert("Xy9!"), @"ECKS RAY yang key NIN ER Exclamation");
+
+    let converter = converter.nonce_form(true);
+    assert_snapshot!(converter.convert("Q"), @"'Q' as in KEH BECK");
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Raf1942).respell(true);
+    assert_snapshot!(converter.convert("ab"), @"apple beer");
+}
+
+#[test]
+fn test_dump_pronunciations() {
+    let converter = init_converter();
+    let mut buf = Vec::new();
+    converter.dump_pronunciations(&mut buf, true).unwrap();
+    let output = String::from_utf8(buf).unwrap();
+    let dumped: Vec<_> = output
+        .lines()
+        .filter(|line| line.starts_with(['j', 'n', '9', '!']))
+        .collect();
+    assert_snapshot!(dumped.join("\n"), @r"
+    j -> Juliett       JEW LEE ETT   ˈdʒuːliˑˈet
+    n -> November      NO VEM BER    noˈvembə
+    9 -> Niner         NIN ER        ˈnaɪnər
+    ! -> Exclamation
+    ");
+}
+
+#[test]
+fn test_similarity() {
+    let bee = CodeWord::new("Bee");
+    assert!((bee.similarity(&bee) - 1.0).abs() < f64::EPSILON);
+    assert_snapshot!(format!("{:.2}", bee.similarity(&CodeWord::new("Dee"))), @"0.88");
+    assert_snapshot!(format!("{:.2}", bee.similarity(&CodeWord::new("Vee"))), @"0.88");
+    assert_snapshot!(format!("{:.2}", bee.similarity(&CodeWord::new("Echo"))), @"0.42");
+    assert_snapshot!(format!("{:.2}", CodeWord::new("Mike").similarity(&CodeWord::new("Nike"))), @"0.92");
+}
+
+#[test]
+fn test_confusable_pairs() {
+    let converter = init_converter();
+    assert!(converter.confusable_pairs(0.85).is_empty());
+
+    let mut overrides_map = HashMap::new();
+    overrides_map.insert('b', "Bee".to_string());
+    overrides_map.insert('v', "Vee".to_string());
+    let converter = converter.with_overrides(overrides_map);
+    let pairs: Vec<_> = converter
+        .confusable_pairs(0.8)
+        .iter()
+        .map(|pair| {
+            let ((a, b), (a_word, b_word)) = (pair.characters(), pair.code_words());
+            let marker = if pair.is_overridden() { " *" } else { "" };
+            format!("{:.2} {a}={a_word} {b}={b_word}{marker}", pair.similarity())
+        })
+        .collect();
+    assert_snapshot!(pairs.join("\n"), @r"
+    0.88 b=Bee v=Vee *
+    0.82 (=LeftParens )=RightParens
+    0.82 [=LeftBracket ]=RightBracket
+    0.80 [=LeftBracket {=LeftBrace
+    0.80 ]=RightBracket }=RightBrace
+    ");
+}
+
+#[test]
+fn test_validate() {
+    for alphabet in [
+        SpellingAlphabet::Nato,
+        SpellingAlphabet::Greek,
+        SpellingAlphabet::Russian,
+    ] {
+        assert_eq!(PhoneticConverter::new(&alphabet).validate(), []);
+    }
+
+    let mut overrides_map = HashMap::new();
+    overrides_map.insert('c', String::new());
+    overrides_map.insert('d', " Delta".to_string());
+    overrides_map.insert('e', "Echo\u{200b}".to_string());
+    overrides_map.insert('f', "Golf".to_string());
+    let converter = init_converter()
+        .override_case(&OverrideCase::Preserve)
+        .with_overrides(overrides_map);
+    let findings: Vec<_> = converter
+        .validate()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    assert_snapshot!(findings.join("\n"), @r#"
+    'c' has an empty code word
+    'd' has the code word " Delta", which starts or ends with whitespace
+    'e' has the code word "Echo\u{200b}", which contains the non-printable character U+200B
+    'f' has the code word "Golf", which doesn't start with that letter
+    'f' and 'g' share the code word "Golf"
+    "#);
+    assert_eq!(
+        converter.validate()[0],
+        Finding::EmptyCodeWord { character: 'c' }
+    );
+}
+
+#[test]
+fn test_validate_missing_characters() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Wabun);
+    assert_eq!(converter.validate(), []);
+
+    let mut overrides_map = HashMap::new();
+    overrides_map.insert('a', "Alfa".to_string());
+    let converter = converter.with_overrides(overrides_map);
+    assert_eq!(converter.validate().len(), 25);
+    assert_eq!(
+        converter.validate()[0],
+        Finding::MissingCharacter { character: 'b' }
+    );
+}
+
+#[test]
+fn test_diff() {
+    let converter = init_converter();
+    assert_eq!(converter.diff(&init_converter()), []);
+
+    let mut overrides_map = HashMap::new();
+    overrides_map.insert('a', "Apple".to_string());
+    overrides_map.insert('é', "EchoAccent".to_string());
+    overrides_map.insert('!', "Bang".to_string());
+    let changes = converter.diff(&init_converter().with_overrides(overrides_map));
+    assert_eq!(
+        changes,
+        [
+            Change::Changed {
+                character: 'a',
+                old: "Alfa".into(),
+                new: "Apple".into(),
+            },
+            Change::Added {
+                character: 'é',
+                code_word: CodeWord::joined(["Echo", "Accent"]),
+            },
+            Change::Changed {
+                character: '!',
+                old: "Exclamation".into(),
+                new: "Bang".into(),
+            },
+        ]
+    );
+    assert_eq!(changes[2].class(), CharacterClass::Symbol);
+
+    let russian = PhoneticConverter::new(&SpellingAlphabet::Russian);
+    let changes = converter.diff(&russian);
+    assert_eq!(
+        changes[0],
+        Change::Removed {
+            character: 'a',
+            code_word: "Alfa".into(),
+        }
+    );
+    assert!(changes.iter().any(|change| matches!(
+        change,
+        Change::Added {
+            character: 'ж', ..
+        }
+    )));
+}
+
+#[test]
+fn test_lookups() {
+    let converter = init_converter();
+    assert_eq!(converter.code_word('x'), Some("x-ray"));
+    assert_eq!(converter.code_word('X'), Some("X-RAY"));
+    assert_eq!(converter.code_word(' '), Some("Space"));
+    assert_eq!(converter.code_word('é'), None);
+    assert!(converter.contains('Z'));
+    assert!(!converter.contains('ß'));
+
+    // Code words are cased the same way as when converting
+    for (alphabet, text) in [
+        (SpellingAlphabet::Nato, "aAzZ9 !"),
+        (SpellingAlphabet::Greek, "αΑσΣςbB"),
+        (SpellingAlphabet::Russian, "жЖйЙ"),
+    ] {
+        let converter = PhoneticConverter::new(&alphabet);
+        for c in text.chars() {
+            let spelled = converter.convert(&c.to_string());
+            assert_eq!(converter.code_word(c), Some(spelled.as_str()), "{c}");
+        }
+    }
+
+    assert_eq!(converter.character_for("xray"), Some('x'));
+    assert_eq!(converter.character_for("LEFT_PARENS"), Some('('));
+    assert_eq!(converter.character_for("niner"), Some('9'));
+    assert_eq!(converter.character_for("Nine"), None);
+
+    assert_eq!(converter.classify('k'), CharacterClass::Letter);
+    assert_eq!(converter.classify('0'), CharacterClass::Digit);
+    assert_eq!(converter.classify('~'), CharacterClass::Symbol);
+    assert_eq!(converter.classify('é'), CharacterClass::Unknown);
+
+    let mut overrides_map = HashMap::new();
+    overrides_map.insert('ß', "Eszett".to_string());
+    overrides_map.insert('a', "Apple".to_string());
+    let converter = converter.with_overrides(overrides_map);
+    assert_eq!(converter.character_for("eszett"), Some('ß'));
+    assert_eq!(converter.character_for("apple"), Some('a'));
+    assert_eq!(converter.character_for("alfa"), None);
+    assert_eq!(converter.classify('ß'), CharacterClass::Letter);
+
+    let converter = PhoneticConverter::new(&SpellingAlphabet::WesternUnion);
+    assert_eq!(converter.character_for("zero"), Some('z'));
+}
+
+#[test]
+fn test_static_code_words() {
+    for alphabet in [
+        SpellingAlphabet::Greek,
+        SpellingAlphabet::Lapd,
+        SpellingAlphabet::Nato,
+        SpellingAlphabet::Wabun,
+    ] {
+        for (character, code_word) in alphabet.initialize() {
+            assert_eq!(alphabet.code_word(character), Some(code_word.as_str()));
+        }
+    }
+    assert_eq!(SpellingAlphabet::Nato.code_word('Ä'), None);
+}
+
+#[test]
+fn test_overrides_from_pairs() {
+    let converter =
+        init_converter().with_overrides([('a', "Able".to_string()), ('9', "Nine".to_string())]);
+    assert_eq!(converter.convert("a9"), "able Nine");
+
+    let mappings = converter.code_words();
+    assert_eq!(mappings[&'a'].as_str(), "Able");
+    assert_eq!(mappings[&'b'].as_str(), "Bravo");
+    assert_eq!(mappings.len(), init_converter().mappings().len());
+    assert!(mappings.keys().is_sorted());
+}
+
+#[test]
+fn test_shared_converters() {
+    let nato = PhoneticConverter::shared(SpellingAlphabet::Nato);
+    assert!(std::ptr::eq(
+        nato,
+        PhoneticConverter::shared(SpellingAlphabet::Nato)
+    ));
+    assert_eq!(nato.convert("Hi"), init_converter().convert("Hi"));
+
+    let mut overrides_map = HashMap::new();
+    overrides_map.insert('h', "Hawaii".to_string());
+    let custom = nato.clone().with_overrides(overrides_map);
+    assert_eq!(custom.convert("Hi"), "HAWAII india");
+    assert_eq!(custom.mappings().len(), nato.mappings().len());
+    assert_eq!(custom.character_for("hotel"), None);
+    assert_eq!(nato.convert("Hi"), "HOTEL india");
+    assert_eq!(nato.character_for("hotel"), Some('h'));
+
+    let lapd = PhoneticConverter::shared(SpellingAlphabet::Lapd);
+    assert_eq!(lapd.convert("Hi"), "HENRY ida");
+
+    let mut overrides_map = HashMap::new();
+    overrides_map.insert('z', "Zebra".to_string());
+    let western_union = PhoneticConverter::shared(SpellingAlphabet::WesternUnion)
+        .clone()
+        .with_overrides(overrides_map);
+    assert_eq!(western_union.character_for("zero"), Some('0'));
+    assert_eq!(western_union.character_for("zebra"), Some('z'));
+}
+
+#[test]
+fn test_write_converted() {
+    let converter = init_converter().nonce_form(true);
+    let mut output = String::from("> ");
+    converter.write_converted("Hi!", &mut output).unwrap();
+    assert_eq!(output, format!("> {}", converter.convert("Hi!")));
+}
+
+#[test]
+fn test_encodings() {
+    let converter = init_converter();
+    assert_snapshot!(converter.convert_encoded("DEADbeef", Encoding::Hex), @"delta echo alfa delta bravo echo echo foxtrot");
+    assert_snapshot!(converter.convert_encoded("JBSW=", Encoding::Base32), @"juliett bravo sierra whiskey Equals");
+    assert_snapshot!(converter.convert_encoded("aB+/", Encoding::Base64), @"Lowercase alfa Uppercase BRAVO Plus ForeSlash");
+    assert_snapshot!(
+        converter.convert_encoded("123E4567-E89B-12D3-A456-426614174000", Encoding::Uuid),
+        @"One Two Tree echo Fower Fife Six Seven / echo Eight Niner bravo / One Two delta Tree / alfa Fower Fife Six / Fower Two Six Six One Fower One Seven Fower Zero Zero Zero"
+    );
+
+    let converter = init_converter().nonce_form(true);
+    assert_snapshot!(converter.convert_encoded("aB", Encoding::Base64), @"Lowercase 'a' as in alfa, Uppercase 'B' as in BRAVO");
+
+    // Case words and nonce form follow the language of the alphabet
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Greek).nonce_form(true);
+    assert_snapshot!(converter.convert_encoded("aΒ", Encoding::Base64), @"Πεζό 'a' όπως στο alfa, Κεφαλαίο 'Β' όπως στο ΒΎΡΩΝ");
+}
+
+#[test]
+fn test_encoding_validation() {
+    assert_eq!(Encoding::Hex.validate("0123456789abcdefABCDEF"), Ok(()));
+    assert_eq!(
+        Encoding::Hex.validate("0x1f"),
+        Err(EncodingError::InvalidCharacter {
+            character: 'x',
+            index: 1
+        })
+    );
+    assert_eq!(Encoding::Hex.validate(""), Err(EncodingError::Empty));
+
+    assert_eq!(Encoding::Base32.validate("JBSWY3DPEHPK3PXP"), Ok(()));
+    assert_eq!(Encoding::Base32.validate("jbswy3dp"), Ok(()));
+    assert_eq!(Encoding::Base32.validate("JBSWY3DPEE"), Ok(()));
+    assert_eq!(
+        Encoding::Base32.validate("JBSWY3DPE"),
+        Err(EncodingError::InvalidLength { length: 9 })
+    );
+    assert_eq!(
+        Encoding::Base32.validate("JBSWY3DPEE===="),
+        Err(EncodingError::InvalidPadding { index: 10 })
+    );
+
+    assert_eq!(Encoding::Base64.validate("aGVsbG8="), Ok(()));
+    assert_eq!(Encoding::Base64.validate("aGVsbG8"), Ok(()));
+    assert_eq!(Encoding::Base64.validate("a-_b"), Ok(()));
+    assert_eq!(
+        Encoding::Base64.validate("a+_b"),
+        Err(EncodingError::InvalidCharacter {
+            character: '+',
+            index: 1
+        })
+    );
+    assert_eq!(
+        Encoding::Base64.validate("aG=sbG8="),
+        Err(EncodingError::InvalidPadding { index: 2 })
+    );
+    assert_eq!(
+        Encoding::Base64.validate("aGVsb"),
+        Err(EncodingError::InvalidLength { length: 5 })
+    );
+
+    assert_eq!(
+        Encoding::Uuid.validate("123e4567-e89b-12d3-a456-426614174000"),
+        Ok(())
+    );
+    assert_eq!(
+        Encoding::Uuid.validate("123e4567e89b12d3a456426614174000"),
+        Err(EncodingError::InvalidCharacter {
+            character: 'e',
+            index: 8
+        })
+    );
+    assert_eq!(
+        Encoding::Uuid.validate("123e4567-e89b"),
+        Err(EncodingError::InvalidLength { length: 13 })
+    );
+    assert_eq!(
+        EncodingError::InvalidCharacter {
+            character: 'x',
+            index: 1
+        }
+        .to_string(),
+        "invalid character 'x' at position 2"
+    );
+}
+
+#[test]
+fn test_ambiguous_glyphs() {
+    let converter = init_converter();
+    assert_snapshot!(converter.convert_annotated("0O1lI5S"), @"Zero (digit) OSCAR (letter) One (digit) lima (letter) INDIA (letter) Fife (digit) SIERRA (letter)");
+    assert_snapshot!(converter.convert_annotated("rnm arm"), @"romeo november (2 letters) mike (letter) Space alfa romeo mike (letter)");
+    assert_snapshot!(converter.convert_annotated("Hi"), @"HOTEL india");
+    assert_eq!(converter.ambiguous_glyphs("B0rn 5"), ["0", "rn", "5"]);
+
+    let tokens = converter.tokens("urn");
+    assert_eq!(tokens[0].ambiguous_glyph(), None);
+    let glyph = tokens[1].ambiguous_glyph().unwrap();
+    assert_eq!((glyph.glyph(), glyph.is_last()), ("rn", false));
+    let glyph = tokens[2].ambiguous_glyph().unwrap();
+    assert_eq!(
+        (glyph.glyph(), glyph.is_last(), glyph.label().as_str()),
+        ("rn", true, "2 letters")
+    );
+
+    let glyphs = ConfusableGlyphs::new()
+        .group(&["vv", "w"])
+        .group(&["", "|"]);
+    assert_eq!(glyphs.groups(), [vec!["vv", "w"], vec!["|"]]);
+    let converter = init_converter().confusable_glyphs(glyphs).nonce_form(true);
+    assert_snapshot!(converter.convert_annotated("w0|"), @"'w' as in whiskey (letter), Zero, Pipe (symbol)");
+    assert_eq!(converter.ambiguous_glyphs("vvvw"), ["vv", "w"]);
+
+    // a skipped glyph's label isn't left on the code word before it
+    let glyphs = ConfusableGlyphs::default().group(&["ж", "x"]);
+    let converter = init_converter()
+        .confusable_glyphs(glyphs)
+        .unknown_chars(&UnknownChars::Skip);
+    assert_snapshot!(converter.convert_annotated("aжx0"), @"alfa x-ray (letter) Zero (digit)");
+}
+
+#[test]
+fn test_checksum() {
+    let key = "a8F3kQ9zL2mX7vB1nC4pR6tY0wE5sD8gH3jK9uM2";
+    let checksum = Checksum::of(key);
+    assert_eq!(checksum.to_string(), "14");
+    for i in 1..key.len() {
+        let mut swapped: Vec<char> = key.chars().collect();
+        swapped.swap(i - 1, i);
+        let swapped: String = swapped.into_iter().collect();
+        if swapped != key {
+            assert_ne!(Checksum::of(&swapped), checksum, "{swapped}");
+        }
+    }
+    assert_eq!(Checksum::of("").value(), 98);
+
+    let converter = init_converter();
+    assert_snapshot!(converter.convert_with_checksum("Key 9"), @"KILO echo yankee Space Niner / Checksum Fife Tree");
+    assert_snapshot!(converter.nonce_form(true).convert_checksum("Key 9"), @"Checksum, Fife, Tree");
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Lapd);
+    assert_snapshot!(converter.convert_with_checksum("Key 9"), @"KING edward young Space Niner / Checksum Five Three");
+}
+
+#[test]
+fn test_verify() {
+    let converter = init_converter();
+    let spoken = converter.convert_checksum("Key 9");
+    assert_eq!(converter.verify("Key 9", &spoken), Ok(()));
+    assert_eq!(converter.verify("Key 9", &spoken.to_uppercase()), Ok(()));
+    assert_eq!(
+        converter.verify("Key 9", &Checksum::of("Key 9").to_string()),
+        Ok(())
+    );
+    let mismatch = converter.verify("Key 9", "Zero Tree").unwrap_err();
+    assert!(matches!(
+        mismatch,
+        ChecksumError::Mismatch { expected, spoken }
+            if expected == Checksum::of("Key 9") && spoken.value() == 3
+    ));
+    assert_eq!(
+        mismatch.to_string(),
+        "the checksum is 53, but 03 was spoken"
+    );
+    assert_eq!(
+        converter.verify("Key 9", "Zero Apple"),
+        Err(ChecksumError::UnknownWord {
+            word: "Apple".to_string()
+        })
+    );
+    assert_eq!(
+        converter.verify("Key 9", "alfa"),
+        Err(ChecksumError::UnknownWord {
+            word: "alfa".to_string()
+        })
+    );
+    assert_eq!(
+        converter.verify("Key 9", "Checksum One Two Tree"),
+        Err(ChecksumError::InvalidLength { length: 3 })
+    );
+
+    let overrides = HashMap::from([('3', "Number Three".to_string())]);
+    let converter = init_converter().with_overrides(overrides);
+    let spoken = converter.convert_checksum("Secret key");
+    assert_snapshot!(spoken, @"Checksum NumberThree Fife");
+    assert_eq!(converter.verify("Secret key", &spoken), Ok(()));
+    assert_eq!(converter.verify("Secret key", "number three, fife"), Ok(()));
+}
@@ -0,0 +1,224 @@
+use std::f64::consts::PI;
+use std::io::{self, Write};
+
+use spellabet::MorseElement;
+
+// The length of the fade in and out at each edge of a tone, which avoids the
+// clicks of starting or stopping a tone abruptly.
+const RAMP_SECONDS: f64 = 0.005;
+
+// The peak amplitude of a tone, leaving some headroom below full scale.
+const AMPLITUDE: f64 = 0.8 * i16::MAX as f64;
+
+/// Synthesizes Morse code elements into audio samples as a sine tone.
+pub struct Synthesizer {
+    /// The character speed in words per minute.
+    wpm: u32,
+    /// The overall speed in words per minute, when slower than the character
+    /// speed, using Farnsworth spacing.
+    farnsworth_wpm: Option<u32>,
+    /// The frequency of the tone in hertz.
+    frequency: u32,
+    /// The number of samples per second.
+    sample_rate: u32,
+}
+
+impl Synthesizer {
+    pub const fn new(
+        wpm: u32,
+        farnsworth_wpm: Option<u32>,
+        frequency: u32,
+        sample_rate: u32,
+    ) -> Self {
+        Self {
+            wpm,
+            farnsworth_wpm,
+            frequency,
+            sample_rate,
+        }
+    }
+
+    /// Renders the elements into 16-bit samples.
+    pub fn render(&self, elements: &[MorseElement]) -> Vec<i16> {
+        // Each unit is the length of a dit, based on the standard word "PARIS"
+        let unit = 1.2 / f64::from(self.wpm);
+        let (letter_gap, word_gap) = match self.farnsworth_wpm {
+            Some(overall) if overall < self.wpm => {
+                // Stretch the gaps to reach the slower overall speed, following
+                // the ARRL's timing for Farnsworth spacing
+                let (c, s) = (f64::from(self.wpm), f64::from(overall));
+                let total_delay = 60.0f64.mul_add(c, -37.2 * s) / (s * c);
+                (3.0 * total_delay / 19.0, 7.0 * total_delay / 19.0)
+            }
+            _ => (3.0 * unit, 7.0 * unit),
+        };
+
+        let mut samples = Vec::new();
+        let mut after_mark = false;
+        for element in elements {
+            match element {
+                MorseElement::Dit | MorseElement::Dah => {
+                    if after_mark {
+                        self.silence(unit, &mut samples);
+                    }
+                    let units = if *element == MorseElement::Dit {
+                        1.0
+                    } else {
+                        3.0
+                    };
+                    self.tone(units * unit, &mut samples);
+                    after_mark = true;
+                    continue;
+                }
+                MorseElement::LetterGap => self.silence(letter_gap, &mut samples),
+                MorseElement::WordGap => self.silence(word_gap, &mut samples),
+                MorseElement::Unknown(_) => {}
+            }
+            after_mark = false;
+        }
+        samples
+    }
+
+    fn silence(&self, seconds: f64, samples: &mut Vec<i16>) {
+        let len = self.sample_count(seconds);
+        samples.resize(samples.len() + len, 0);
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn tone(&self, seconds: f64, samples: &mut Vec<i16>) {
+        let len = self.sample_count(seconds);
+        let ramp = self.sample_count(RAMP_SECONDS).min(len / 2).max(1);
+        let rate = f64::from(self.sample_rate);
+        let step = 2.0 * PI * f64::from(self.frequency) / rate;
+        samples.extend((0..len).map(|i| {
+            // Shape each edge with a raised cosine
+            let edge = i.min(len - 1 - i);
+            let envelope = if edge < ramp {
+                (1.0 - (PI * edge as f64 / ramp as f64).cos()) / 2.0
+            } else {
+                1.0
+            };
+            (AMPLITUDE * envelope * (step * i as f64).sin()).round() as i16
+        }));
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn sample_count(&self, seconds: f64) -> usize {
+        (seconds * f64::from(self.sample_rate)).round() as usize
+    }
+}
+
+/// Writes the samples to the writer as a mono, 16-bit PCM WAV file.
+pub fn write_wav(mut writer: impl Write, sample_rate: u32, samples: &[i16]) -> io::Result<()> {
+    let data_len = samples
+        .len()
+        .checked_mul(2)
+        .and_then(|len| u32::try_from(len).ok())
+        .filter(|len| *len <= u32::MAX - 36)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "audio too long for WAV"))?;
+
+    writer.write_all(b"RIFF")?;
+    writer.write_all(&(36 + data_len).to_le_bytes())?;
+    writer.write_all(b"WAVE")?;
+
+    writer.write_all(b"fmt ")?;
+    writer.write_all(&16u32.to_le_bytes())?; // chunk size
+    writer.write_all(&1u16.to_le_bytes())?; // PCM format
+    writer.write_all(&1u16.to_le_bytes())?; // channels
+    writer.write_all(&sample_rate.to_le_bytes())?;
+    writer.write_all(&(sample_rate * 2).to_le_bytes())?; // byte rate
+    writer.write_all(&2u16.to_le_bytes())?; // block align
+    writer.write_all(&16u16.to_le_bytes())?; // bits per sample
+
+    writer.write_all(b"data")?;
+    writer.write_all(&data_len.to_le_bytes())?;
+    for sample in samples {
+        writer.write_all(&sample.to_le_bytes())?;
+    }
+    writer.flush()
+}
+
+/// Reads the samples and sample rate from a mono, 16-bit PCM WAV file.
+pub fn read_wav(bytes: &[u8]) -> Result<(u32, Vec<i16>), String> {
+    if bytes.len() < 12 || &bytes[..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err("not a WAV file".to_string());
+    }
+
+    let mut sample_rate = None;
+    let mut rest = &bytes[12..];
+    while rest.len() >= 8 {
+        let (id, size) = (&rest[..4], read_u32(&rest[4..8]) as usize);
+        let body = rest
+            .get(8..8 + size)
+            .ok_or_else(|| "truncated chunk".to_string())?;
+        match id {
+            b"fmt " => {
+                if body.len() < 16 {
+                    return Err("truncated format chunk".to_string());
+                }
+                let (format, channels, bits) = (
+                    read_u16(&body[0..2]),
+                    read_u16(&body[2..4]),
+                    read_u16(&body[14..16]),
+                );
+                if format != 1 || channels != 1 || bits != 16 {
+                    return Err("not mono, 16-bit PCM audio".to_string());
+                }
+                sample_rate = Some(read_u32(&body[4..8]));
+            }
+            b"data" => {
+                let sample_rate = sample_rate.ok_or_else(|| "missing format chunk".to_string())?;
+                let samples = body
+                    .chunks_exact(2)
+                    .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+                    .collect();
+                return Ok((sample_rate, samples));
+            }
+            _ => {}
+        }
+        // chunks are padded to an even size
+        rest = rest.get(8 + size + size % 2..).unwrap_or_default();
+    }
+    Err("missing data chunk".to_string())
+}
+
+fn read_u16(bytes: &[u8]) -> u16 {
+    u16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[test]
+fn verify_timing() {
+    use spellabet::MorseEncoder;
+
+    // At 20 WPM and 1000 samples per second, a unit is 60 samples
+    let synthesizer = Synthesizer::new(20, None, 400, 1000);
+    let elements = MorseEncoder::new().elements("e t");
+    let samples = synthesizer.render(&elements);
+    assert_eq!(samples.len(), 60 + 7 * 60 + 3 * 60);
+    assert!(samples[..60].iter().any(|sample| *sample != 0));
+    assert!(samples[60..480].iter().all(|sample| *sample == 0));
+    assert_eq!(samples[0], 0);
+}
+
+#[test]
+fn verify_wav_header() {
+    let mut buf = Vec::new();
+    write_wav(&mut buf, 8000, &[0, 1, -1]).unwrap();
+    assert_eq!(buf.len(), 44 + 6);
+    assert_eq!(&buf[..4], b"RIFF");
+    assert_eq!(&buf[8..16], b"WAVEfmt ");
+    assert_eq!(u32::from_le_bytes(buf[24..28].try_into().unwrap()), 8000);
+    assert_eq!(u32::from_le_bytes(buf[40..44].try_into().unwrap()), 6);
+}
+
+#[test]
+fn verify_wav_round_trip() {
+    let mut buf = Vec::new();
+    write_wav(&mut buf, 8000, &[0, 1, -1]).unwrap();
+    assert_eq!(read_wav(&buf), Ok((8000, vec![0, 1, -1])));
+    assert!(read_wav(&buf[..20]).is_err());
+}
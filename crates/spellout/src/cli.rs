@@ -1,6 +1,13 @@
+use std::path::PathBuf;
+
 use clap::{Parser, ValueEnum};
+use spellabet::{
+    ConfusableGlyphs, Encoding, Era, OverrideCase, SpellingAlphabet, SymbolVocabulary,
+    UnknownChars, UppercaseMarkup, WordStyle,
+};
 
 #[derive(Parser)]
+#[allow(clippy::struct_excessive_bools)]
 #[command(author, about, long_about = None)]
 #[command(version = get_version())]
 pub struct Cli {
@@ -9,6 +16,131 @@ pub struct Cli {
     #[arg(value_enum, default_value_t = Alphabet::Nato)]
     pub alphabet: Alphabet,
 
+    /// Which output format to convert into
+    ///
+    /// Morse output uses ITU Morse code, with procedural signs written as
+    /// letters within angle brackets like "<AR>". Respelling output replaces
+    /// code words with their standard pronunciation, like "AL FAH" for "Alfa",
+    /// when one is known.
+    #[arg(short, long, env = "SPELLOUT_FORMAT")]
+    #[arg(value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Spell the input as machine-encoded data
+    ///
+    /// Hex, Base32, and UUID letters are case-insensitive, so they're spelled
+    /// in lowercase. Base64 letters are each preceded by "Uppercase" or
+    /// "Lowercase". UUID hyphens become group breaks, written as " / ".
+    /// Applies to text and respelling output.
+    #[arg(long = "as", value_name = "ENCODING", value_enum)]
+    #[arg(conflicts_with = "wav")]
+    pub encoding: Option<DataEncoding>,
+
+    /// Fail unless each input string is valid in the `--as` encoding
+    #[arg(long, requires = "encoding")]
+    pub validate: bool,
+
+    /// Follow each input string with a spoken checksum to read back
+    ///
+    /// The checksum is two digits, spelled with the spelling alphabet like
+    /// "Checksum Fife Tree", that the listener can read back with the
+    /// `--verify` option to confirm they copied the string correctly. Applies
+    /// to text and respelling output.
+    #[arg(long, conflicts_with = "wav")]
+    pub checksum: bool,
+
+    /// Mark characters that are easily misread from a screen
+    ///
+    /// Each ambiguous glyph, like "0" and "O", or "rn" and "m", is followed by
+    /// a label like "(digit)" or "(letter)", and each input string is followed
+    /// by a summary line counting them. Applies to text and respelling output.
+    #[arg(long, conflicts_with_all = ["encoding", "wav"])]
+    pub annotate: bool,
+
+    /// The groups of glyphs that `--annotate` treats as alike
+    ///
+    /// Groups are separated by commas, and the glyphs in each group by
+    /// slashes. The default is "0/O,1/l/I,5/S,rn/m".
+    #[arg(long, value_name = "GROUPS", requires = "annotate")]
+    #[arg(value_parser = parse_confusables)]
+    pub confusables: Option<ConfusableGlyphs>,
+
+    /// Write the input as Morse code audio to a WAV file
+    ///
+    /// All of the input strings are sent as one transmission, separated by
+    /// word gaps. The audio is synthesized locally as a sine tone, unless a
+    /// `--sound-pack` is given to speak the code words instead. The output
+    /// format, whether given by `--format` or `SPELLOUT_FORMAT`, doesn't apply
+    /// and can't be set along with it.
+    #[arg(long, value_name = "FILE")]
+    #[arg(conflicts_with_all = ["format", "dump_alphabet", "list_alphabets"])]
+    pub wav: Option<PathBuf>,
+
+    /// Write the spoken code words to the WAV file, using clips from a sound
+    /// pack directory
+    ///
+    /// The directory holds a `manifest.txt` file listing one code word per
+    /// line, like "alfa", or "alfa=alfa-clip.wav" to name its clip, which
+    /// otherwise defaults to "alfa.wav". Clips must be mono, 16-bit PCM WAV
+    /// files with a shared sample rate. Whitespace in the input becomes a
+    /// pause.
+    #[arg(long, value_name = "DIR", requires = "wav")]
+    pub sound_pack: Option<PathBuf>,
+
+    /// The silence between spoken characters in milliseconds
+    ///
+    /// Applies to sound packs and SSML output.
+    #[arg(long, value_name = "MS", default_value_t = 250)]
+    #[arg(hide_short_help = true)]
+    pub char_gap: u32,
+
+    /// The silence between spoken words in milliseconds
+    ///
+    /// Applies to sound packs and SSML output.
+    #[arg(long, value_name = "MS", default_value_t = 750)]
+    #[arg(hide_short_help = true)]
+    pub word_gap: u32,
+
+    /// How to signal uppercase letters in SSML output
+    #[arg(long, value_name = "MARKUP", value_enum)]
+    #[arg(default_value_t = Uppercase::Emphasis, hide_short_help = true)]
+    pub uppercase: Uppercase,
+
+    /// The Morse code character speed in words per minute
+    #[arg(long, default_value_t = 20, requires = "wav")]
+    #[arg(value_parser = clap::value_parser!(u32).range(5..=60))]
+    #[arg(hide_short_help = true)]
+    pub wpm: u32,
+
+    /// The overall Morse code speed in words per minute, using Farnsworth
+    /// spacing
+    ///
+    /// Characters are sent at the `--wpm` speed, with longer gaps between
+    /// characters and words to reach this slower overall speed.
+    #[arg(long, value_name = "WPM", requires = "wav")]
+    #[arg(value_parser = clap::value_parser!(u32).range(5..=60))]
+    #[arg(hide_short_help = true)]
+    pub farnsworth: Option<u32>,
+
+    /// The frequency of the Morse code tone in hertz
+    #[arg(long, value_name = "HZ", default_value_t = 600, requires = "wav")]
+    #[arg(value_parser = clap::value_parser!(u32).range(100..=2000))]
+    #[arg(hide_short_help = true)]
+    pub tone: u32,
+
+    /// The sample rate of the Morse code audio in hertz
+    #[arg(long, value_name = "HZ", default_value_t = 8000, requires = "wav")]
+    #[arg(value_parser = clap::value_parser!(u32).range(8000..=192_000))]
+    #[arg(hide_short_help = true)]
+    pub sample_rate: u32,
+
+    /// Which vocabulary to use for digits and symbols
+    ///
+    /// Defaults to the vocabulary of the spelling alphabet's own language.
+    #[arg(short, long, env = "SPELLOUT_SYMBOLS", value_enum)]
+    #[arg(value_name = "VOCABULARY")]
+    pub symbols: Option<Symbols>,
+
     /// Define overrides for spelling alphabet code words
     ///
     /// Provide a comma-separated list of character=word pairs like
@@ -16,6 +148,11 @@ pub struct Cli {
     #[arg(short, long, env = "SPELLOUT_OVERRIDES")]
     pub overrides: Option<String>,
 
+    /// How to normalize the capitalization of override code words
+    #[arg(long, env = "SPELLOUT_OVERRIDE_CASE", value_name = "CASE")]
+    #[arg(value_enum, default_value_t = CaseStyle::Pascal)]
+    pub override_case: CaseStyle,
+
     /// Display the spelling alphabet and exit
     ///
     /// Shows only letters by default; add the `--verbose` flag to also show
@@ -23,11 +160,95 @@ pub struct Cli {
     #[arg(long)]
     pub dump_alphabet: bool,
 
+    /// Include the pronunciation of each code word when displaying the
+    /// spelling alphabet
+    ///
+    /// Shows the respelling and IPA transcription of each code word with a
+    /// known pronunciation.
+    #[arg(long, requires = "dump_alphabet")]
+    pub pronunciation: bool,
+
+    /// Display the available spelling alphabets and exit
+    ///
+    /// Lists each alphabet's name, year of introduction, and source. Use the
+    /// `--era` option to choose which alphabets are shown.
+    #[arg(long)]
+    pub list_alphabets: bool,
+
+    /// Which era of spelling alphabets to list
+    #[arg(long, value_enum, default_value_t = EraFilter::Current)]
+    #[arg(requires = "list_alphabets")]
+    pub era: EraFilter,
+
+    /// Check the spelling alphabet for code words that sound alike and exit
+    ///
+    /// Lists the riskiest pairs of code words, from most to least alike, after
+    /// applying any `--symbols` and `--overrides`. Fails when an override
+    /// sounds at least as alike as the threshold to any other code word; pairs
+    /// of built-in code words are only listed.
+    #[arg(long, conflicts_with_all = ["diff", "verify"])]
+    pub check: bool,
+
+    /// How alike an override may sound to another code word before `--check`
+    /// fails, from 0.0 to 1.0
+    #[arg(long, default_value_t = 0.85, value_name = "SIMILARITY")]
+    #[arg(value_parser = parse_similarity, requires = "check")]
+    #[arg(conflicts_with_all = ["diff", "verify"], hide_short_help = true)]
+    pub threshold: f64,
+
+    /// How many of the riskiest pairs `--check` lists
+    #[arg(long, default_value_t = 10, value_name = "COUNT")]
+    #[arg(requires = "check", conflicts_with_all = ["diff", "verify"])]
+    #[arg(hide_short_help = true)]
+    pub top: usize,
+
+    /// Compare the code words of another spelling alphabet to this one and exit
+    ///
+    /// Lists the characters that are added, removed, or changed from the given
+    /// alphabet to the `--alphabet`, grouped as letters, digits, and symbols.
+    /// Any `--symbols` and `--overrides` apply to the `--alphabet` only, so
+    /// that their effect can be audited, like "spellout --diff nato --overrides
+    /// a=apple".
+    #[arg(long, value_enum, value_name = "ALPHABET", conflicts_with = "verify")]
+    pub diff: Option<Alphabet>,
+
+    /// Which format to show the `--diff` changes in
+    #[arg(long, value_enum, default_value_t = DiffFormat::Table, value_name = "FORMAT")]
+    #[arg(requires = "diff", conflicts_with = "verify", hide_short_help = true)]
+    pub diff_format: DiffFormat,
+
+    /// Confirm that the input string was copied correctly, using its spoken
+    /// checksum, and exit
+    ///
+    /// The checksum is the one given by `--checksum`, read back as digits like
+    /// "53", or as code words of the spelling alphabet like "Fife Tree". Fails
+    /// when the checksum doesn't match the string.
+    #[arg(long, value_name = "CHECKSUM", requires = "input")]
+    pub verify: Option<String>,
+
     /// Expand output into nonce form like "'A' as in ALFA"
     #[arg(short, long, env = "SPELLOUT_NONCE_FORM")]
     #[arg(value_parser = clap::builder::FalseyValueParser::new())]
     pub nonce_form: bool,
 
+    /// Follow accented letters with code words for their marks
+    ///
+    /// Accented letters are always spelled using their base letter; this also
+    /// names each mark, like the Greek tonos, when the alphabet defines one.
+    #[arg(short, long, env = "SPELLOUT_MARK_ACCENTS")]
+    #[arg(value_parser = clap::builder::FalseyValueParser::new())]
+    pub mark_accents: bool,
+
+    /// How to render code words made of several words
+    #[arg(short, long, env = "SPELLOUT_WORD_STYLE", value_name = "STYLE")]
+    #[arg(value_enum, default_value_t = Style::Pascal)]
+    pub word_style: Style,
+
+    /// How to handle characters that can't be converted
+    #[arg(short, long, env = "SPELLOUT_UNKNOWN", value_name = "POLICY")]
+    #[arg(value_enum, default_value_t = Unknown::Keep)]
+    pub unknown: Unknown,
+
     /// Use verbose output
     ///
     /// Include the input characters along with each line's output.
@@ -52,22 +273,288 @@ pub struct Cli {
     pub generate: Option<Asset>,
 }
 
+#[derive(Clone, Debug, ValueEnum)]
+pub enum DiffFormat {
+    /// JSON object with a list of changes for each group
+    Json,
+    /// Readable table
+    Table,
+}
+
+// Historical alphabets are hidden from the help output, but remain available.
 #[derive(Clone, Debug, ValueEnum)]
 pub enum Alphabet {
+    /// Arabic letter names
+    Arabic,
+    /// Bulgarian (Cyrillic)
+    Bulgarian,
+    /// Greek, with NATO for Latin letters
+    Greek,
+    /// Hebrew letter names
+    Hebrew,
+    /// ICAO draft of 1951
+    #[value(name = "icao-1951-draft", hide = true)]
+    Icao1951Draft,
+    /// ITU of 1927
+    #[value(name = "itu-1927", hide = true)]
+    Itu1927,
+    /// ITU of 1938
+    #[value(name = "itu-1938", hide = true)]
+    Itu1938,
     /// Joint Army/Navy
+    #[value(hide = true)]
     Jan,
+    /// Korean Hangul jamo names
+    Korean,
     /// Los Angeles Police Department
     Lapd,
     /// North Atlantic Treaty Organization
     Nato,
+    /// Royal Air Force, 1924 to 1942
+    #[value(name = "raf-1924", hide = true)]
+    Raf1924,
+    /// Royal Air Force, 1942 to 1943
+    #[value(name = "raf-1942", hide = true)]
+    Raf1942,
     /// Royal Navy
+    #[value(hide = true)]
     RoyalNavy,
+    /// Russian (Cyrillic)
+    Russian,
+    /// Ukrainian (Cyrillic)
+    Ukrainian,
     /// United States Financial Industry
     UsFinancial,
+    /// United States telephone operators of the 1920s
+    #[value(hide = true)]
+    UsTelephone,
+    /// Japanese Wabun (i-ro-ha) for kana
+    Wabun,
     /// Western Union
+    #[value(hide = true)]
     WesternUnion,
 }
 
+impl From<Alphabet> for SpellingAlphabet {
+    fn from(alphabet: Alphabet) -> Self {
+        match alphabet {
+            Alphabet::Arabic => Self::Arabic,
+            Alphabet::Bulgarian => Self::Bulgarian,
+            Alphabet::Greek => Self::Greek,
+            Alphabet::Hebrew => Self::Hebrew,
+            Alphabet::Icao1951Draft => Self::Icao1951Draft,
+            Alphabet::Itu1927 => Self::Itu1927,
+            Alphabet::Itu1938 => Self::Itu1938,
+            Alphabet::Jan => Self::Jan,
+            Alphabet::Korean => Self::Korean,
+            Alphabet::Lapd => Self::Lapd,
+            Alphabet::Nato => Self::Nato,
+            Alphabet::Raf1924 => Self::Raf1924,
+            Alphabet::Raf1942 => Self::Raf1942,
+            Alphabet::RoyalNavy => Self::RoyalNavy,
+            Alphabet::Russian => Self::Russian,
+            Alphabet::Ukrainian => Self::Ukrainian,
+            Alphabet::UsFinancial => Self::UsFinancial,
+            Alphabet::UsTelephone => Self::UsTelephone,
+            Alphabet::Wabun => Self::Wabun,
+            Alphabet::WesternUnion => Self::WesternUnion,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Format {
+    /// Spelling alphabet code words
+    Text,
+    /// Morse code, like "... --- ..."
+    Morse,
+    /// Pronunciation respellings, like "AL FAH"
+    Respelling,
+    /// Speech Synthesis Markup Language for text-to-speech engines
+    Ssml,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum DataEncoding {
+    /// Base32, like TOTP secrets
+    Base32,
+    /// Base64, standard or URL-safe
+    Base64,
+    /// Hexadecimal, like hashes
+    Hex,
+    /// UUIDs, like "123e4567-e89b-12d3-a456-426614174000"
+    Uuid,
+}
+
+impl From<DataEncoding> for Encoding {
+    fn from(encoding: DataEncoding) -> Self {
+        match encoding {
+            DataEncoding::Base32 => Self::Base32,
+            DataEncoding::Base64 => Self::Base64,
+            DataEncoding::Hex => Self::Hex,
+            DataEncoding::Uuid => Self::Uuid,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Symbols {
+    /// Arabic digit and symbol names
+    Arabic,
+    /// Bulgarian digit and symbol names
+    Bulgarian,
+    /// Dutch digit and symbol names
+    Dutch,
+    /// English digit and symbol names
+    English,
+    /// Unicode names, like "solidus"
+    Formal,
+    /// French digit and symbol names
+    French,
+    /// German digit and symbol names
+    German,
+    /// Greek digit and symbol names
+    Greek,
+    /// Hebrew digit and symbol names
+    Hebrew,
+    /// Italian digit and symbol names
+    Italian,
+    /// Japanese digit and symbol names
+    Japanese,
+    /// Korean digit and symbol names
+    Korean,
+    /// Radiotelephony, like "niner" and "stroke"
+    Military,
+    /// Programmer jargon, like "bang" and "splat"
+    Programmer,
+    /// Russian digit and symbol names
+    Russian,
+    /// Spanish digit and symbol names
+    Spanish,
+    /// Ukrainian digit and symbol names
+    Ukrainian,
+}
+
+impl From<Symbols> for SymbolVocabulary {
+    fn from(symbols: Symbols) -> Self {
+        match symbols {
+            Symbols::Arabic => Self::Arabic,
+            Symbols::Bulgarian => Self::Bulgarian,
+            Symbols::Dutch => Self::Dutch,
+            Symbols::English => Self::English,
+            Symbols::Formal => Self::Formal,
+            Symbols::French => Self::French,
+            Symbols::German => Self::German,
+            Symbols::Greek => Self::Greek,
+            Symbols::Hebrew => Self::Hebrew,
+            Symbols::Italian => Self::Italian,
+            Symbols::Japanese => Self::Japanese,
+            Symbols::Korean => Self::Korean,
+            Symbols::Military => Self::Military,
+            Symbols::Programmer => Self::Programmer,
+            Symbols::Russian => Self::Russian,
+            Symbols::Spanish => Self::Spanish,
+            Symbols::Ukrainian => Self::Ukrainian,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum CaseStyle {
+    /// Join capitalized words into one code word
+    Pascal,
+    /// Keep code words exactly as given
+    Preserve,
+    /// Capitalize each word, keeping hyphens and spaces like "X-Ray"
+    Title,
+}
+
+impl From<CaseStyle> for OverrideCase {
+    fn from(style: CaseStyle) -> Self {
+        match style {
+            CaseStyle::Pascal => Self::Pascal,
+            CaseStyle::Preserve => Self::Preserve,
+            CaseStyle::Title => Self::Title,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Style {
+    /// Lowercase words joined by hyphens like "double-quote"
+    Kebab,
+    /// Code words as written, with capitalized words joined
+    Pascal,
+    /// Uppercase words joined by underscores
+    ScreamingSnake,
+    /// Lowercase words separated by spaces like "double quote"
+    Spaced,
+}
+
+impl From<Style> for WordStyle {
+    fn from(style: Style) -> Self {
+        match style {
+            Style::Kebab => Self::Kebab,
+            Style::Pascal => Self::Pascal,
+            Style::ScreamingSnake => Self::ScreamingSnake,
+            Style::Spaced => Self::Spaced,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Unknown {
+    /// Keep the characters unconverted
+    Keep,
+    /// Leave the characters out
+    Skip,
+}
+
+impl From<Unknown> for UnknownChars {
+    fn from(unknown: Unknown) -> Self {
+        match unknown {
+            Unknown::Keep => Self::Keep,
+            Unknown::Skip => Self::Skip,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum Uppercase {
+    /// Strong emphasis
+    Emphasis,
+    /// Raised pitch
+    Prosody,
+}
+
+impl From<Uppercase> for UppercaseMarkup {
+    fn from(uppercase: Uppercase) -> Self {
+        match uppercase {
+            Uppercase::Emphasis => Self::Emphasis,
+            Uppercase::Prosody => Self::Prosody,
+        }
+    }
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+pub enum EraFilter {
+    /// Alphabets in use today
+    Current,
+    /// Superseded alphabets
+    Historical,
+    /// All alphabets
+    All,
+}
+
+impl EraFilter {
+    pub const fn matches(&self, era: Era) -> bool {
+        matches!(
+            (self, era),
+            (Self::All, _) | (Self::Current, Era::Current) | (Self::Historical, Era::Historical)
+        )
+    }
+}
+
 #[derive(Clone, Debug, ValueEnum)]
 pub enum Asset {
     /// Manual page in ROFF format
@@ -84,7 +571,47 @@ pub enum Asset {
     Zsh,
 }
 
+fn parse_similarity(value: &str) -> Result<f64, String> {
+    let similarity: f64 = value
+        .parse()
+        .map_err(|_| format!("`{value}` isn't a number"))?;
+    if (0.0..=1.0).contains(&similarity) {
+        Ok(similarity)
+    } else {
+        Err("similarity must be from 0.0 to 1.0".to_string())
+    }
+}
+
+fn parse_confusables(value: &str) -> Result<ConfusableGlyphs, String> {
+    let mut glyphs = ConfusableGlyphs::new();
+    for group in value.split(',') {
+        let group: Vec<&str> = group.split('/').collect();
+        if group.len() < 2 || group.contains(&"") {
+            return Err(format!(
+                "`{}` isn't a group of two or more glyphs",
+                group.join("/")
+            ));
+        }
+        glyphs = glyphs.group(&group);
+    }
+    Ok(glyphs)
+}
+
 fn get_version() -> &'static str {
     // fallback if compiling from a source tarball without git
     option_env!("SPELLOUT_VERSION").unwrap_or(env!("CARGO_PKG_VERSION"))
 }
+
+#[test]
+fn verify_hidden_alphabets() {
+    for alphabet in Alphabet::value_variants() {
+        let value = alphabet.to_possible_value().unwrap();
+        let era = SpellingAlphabet::from(alphabet.clone()).era();
+        assert_eq!(
+            value.is_hide_set(),
+            era == Era::Historical,
+            "{}",
+            value.get_name()
+        );
+    }
+}
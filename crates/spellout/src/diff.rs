@@ -0,0 +1,127 @@
+use std::fmt::Write;
+
+use spellabet::{Change, CharacterClass};
+
+// The character groups in the order they're shown, with their names.
+const GROUPS: [(CharacterClass, &str); 3] = [
+    (CharacterClass::Letter, "Letters"),
+    (CharacterClass::Digit, "Digits"),
+    (CharacterClass::Symbol, "Symbols"),
+];
+
+/// Prints the changes as a table, with a heading for each group of characters
+/// that has any.
+pub fn print_table(changes: &[Change]) {
+    let width = changes
+        .iter()
+        .filter_map(|change| match change {
+            Change::Changed { old, .. } => Some(old.as_str().chars().count()),
+            _ => None,
+        })
+        .max()
+        .unwrap_or_default();
+
+    for (class, name) in GROUPS {
+        let mut group = changes
+            .iter()
+            .filter(|change| change.class() == class)
+            .peekable();
+        if group.peek().is_none() {
+            continue;
+        }
+        println!("{name}:");
+        for change in group {
+            match change {
+                Change::Added {
+                    character,
+                    code_word,
+                } => println!("  + {character}  {code_word}"),
+                Change::Changed {
+                    character,
+                    old,
+                    new,
+                } => println!("  ~ {character}  {:<width$} -> {new}", old.as_str()),
+                Change::Removed {
+                    character,
+                    code_word,
+                } => println!("  - {character}  {code_word}"),
+            }
+        }
+    }
+}
+
+/// Returns the changes as a JSON object, with a list of changes for each group
+/// of characters.
+pub fn to_json(changes: &[Change]) -> String {
+    let mut json = String::from("{");
+    for (i, (class, name)) in GROUPS.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        write!(json, "\n  \"{}\": [", name.to_lowercase()).unwrap();
+        let group: Vec<_> = changes
+            .iter()
+            .filter(|change| change.class() == *class)
+            .map(change_to_json)
+            .collect();
+        if !group.is_empty() {
+            write!(json, "\n    {}\n  ", group.join(",\n    ")).unwrap();
+        }
+        json.push(']');
+    }
+    json.push_str("\n}");
+    json
+}
+
+fn change_to_json(change: &Change) -> String {
+    let character = quote(change.character().encode_utf8(&mut [0; 4]));
+    match change {
+        Change::Added { code_word, .. } => format!(
+            "{{\"character\": {character}, \"change\": \"added\", \"new\": {}}}",
+            quote(code_word.as_str())
+        ),
+        Change::Changed { old, new, .. } => format!(
+            "{{\"character\": {character}, \"change\": \"changed\", \"old\": {}, \"new\": {}}}",
+            quote(old.as_str()),
+            quote(new.as_str())
+        ),
+        Change::Removed { code_word, .. } => format!(
+            "{{\"character\": {character}, \"change\": \"removed\", \"old\": {}}}",
+            quote(code_word.as_str())
+        ),
+    }
+}
+
+// Quote text as a JSON string, escaping the characters that JSON requires.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => write!(quoted, "\\u{:04x}", u32::from(c)).unwrap(),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[test]
+fn verify_json() {
+    use spellabet::{PhoneticConverter, SpellingAlphabet};
+
+    let nato = PhoneticConverter::new(&SpellingAlphabet::Nato);
+    let lapd = PhoneticConverter::new(&SpellingAlphabet::Lapd);
+    let changes: Vec<_> = nato.diff(&lapd).into_iter().take(1).collect();
+    assert_eq!(
+        to_json(&changes),
+        "{\n  \"letters\": [\n    {\"character\": \"a\", \"change\": \"changed\", \"old\": \
+         \"Alfa\", \"new\": \"Adam\"}\n  ],\n  \"digits\": [],\n  \"symbols\": []\n}"
+    );
+    assert_eq!(quote("\"\\\u{1}"), "\"\\\"\\\\\\u0001\"");
+}
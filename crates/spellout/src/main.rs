@@ -2,20 +2,31 @@
 #![warn(clippy::nursery, clippy::pedantic)]
 
 use std::collections::HashMap;
-use std::io::{self, BufRead, IsTerminal};
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, IsTerminal};
+use std::path::PathBuf;
 
 use anyhow::{Context, Result};
 use clap::error::{ContextKind, ContextValue, ErrorKind};
-use clap::{CommandFactory, Parser};
+use clap::{CommandFactory, Parser, ValueEnum};
 use clap_complete::Shell;
-use spellabet::{PhoneticConverter, SpellingAlphabet};
+use spellabet::{
+    Encoding, MorseElement, MorseEncoder, OverrideCase, PhoneticConverter, SpellingAlphabet,
+    SsmlOptions, SymbolVocabulary, UnknownChars, UppercaseMarkup, WordStyle,
+};
 
-use crate::cli::{Alphabet, Asset, Cli};
+use crate::audio::Synthesizer;
+use crate::cli::{Alphabet, Asset, Cli, DiffFormat, EraFilter, Format};
+use crate::sound_pack::SoundPack;
 
+mod audio;
 mod cli;
+mod diff;
+mod sound_pack;
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    check_morse_conflicts(&cli).unwrap_or_else(|err| err.exit());
 
     if let Some(asset) = cli.generate {
         match asset {
@@ -29,20 +40,24 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
-    let alphabet = match cli.alphabet {
-        Alphabet::Jan => SpellingAlphabet::Jan,
-        Alphabet::Lapd => SpellingAlphabet::Lapd,
-        Alphabet::Nato => SpellingAlphabet::Nato,
-        Alphabet::RoyalNavy => SpellingAlphabet::RoyalNavy,
-        Alphabet::UsFinancial => SpellingAlphabet::UsFinancial,
-        Alphabet::WesternUnion => SpellingAlphabet::WesternUnion,
-    };
+    if cli.list_alphabets {
+        list_alphabets(&cli.era);
+        return Ok(());
+    }
 
-    let mut converter = PhoneticConverter::new(&alphabet).nonce_form(cli.nonce_form);
+    let unknown_chars = UnknownChars::from(cli.unknown.clone());
+    let encoding = cli.encoding.map(Encoding::from);
+    let validation = encoding.filter(|_| cli.validate);
+    let mode = TextMode::new(encoding, cli.annotate);
+    let converter = build_converter(&cli, SpellingAlphabet::from(cli.alphabet.clone()))?;
 
-    if let Some(overrides_str) = cli.overrides {
-        let overrides_map = parse_overrides(&overrides_str).context("Failed to parse overrides")?;
-        converter = converter.with_overrides(overrides_map);
+    if cli.check || cli.diff.is_some() || cli.verify.is_some() {
+        return analyze(&cli, &converter);
+    }
+
+    if cli.dump_alphabet && cli.pronunciation {
+        converter.dump_pronunciations(&mut io::stdout(), cli.verbose)?;
+        return Ok(());
     }
 
     if cli.dump_alphabet {
@@ -50,6 +65,45 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    let mut encoder = if let (Some(path), Some(dir)) = (&cli.wav, &cli.sound_pack) {
+        Encoder::Speech {
+            converter,
+            pack: SoundPack::load(dir).context("Failed to load sound pack")?,
+            char_gap: cli.char_gap,
+            word_gap: cli.word_gap,
+            path: path.clone(),
+            inputs: Vec::new(),
+        }
+    } else if let Some(path) = cli.wav {
+        if cli
+            .farnsworth
+            .is_some_and(|farnsworth| farnsworth > cli.wpm)
+        {
+            anyhow::bail!("Farnsworth speed must not be faster than the character speed");
+        }
+        Encoder::Audio {
+            morse: MorseEncoder::new().unknown_chars(&UnknownChars::Skip),
+            synthesizer: Synthesizer::new(cli.wpm, cli.farnsworth, cli.tone, cli.sample_rate),
+            sample_rate: cli.sample_rate,
+            path,
+            elements: Vec::new(),
+        }
+    } else {
+        match cli.format {
+            Format::Text => Encoder::Text(converter, mode, cli.checksum),
+            Format::Morse => Encoder::Morse(MorseEncoder::new().unknown_chars(&unknown_chars)),
+            Format::Respelling => Encoder::Text(converter.respell(true), mode, cli.checksum),
+            Format::Ssml => {
+                let options = SsmlOptions::new()
+                    .char_break(cli.char_gap)
+                    .word_break(cli.word_gap)
+                    .uppercase(&UppercaseMarkup::from(cli.uppercase))
+                    .nonce_form(cli.nonce_form);
+                Encoder::Ssml(converter, options)
+            }
+        }
+    };
+
     if cli.input.is_empty() {
         // Check standard input
         if io::stdin().is_terminal() {
@@ -67,24 +121,307 @@ fn main() -> Result<()> {
             let lines = io::stdin().lock().lines();
             for line in lines {
                 let input = line.context("Failed to read line from stdin")?;
-                process_input(&input, &converter, cli.verbose);
+                validate_input(&input, validation)?;
+                process_input(&input, &mut encoder, cli.verbose);
             }
         }
+    } else {
+        for input in cli.input {
+            validate_input(&input, validation)?;
+            process_input(&input, &mut encoder, cli.verbose);
+        }
+    }
+
+    encoder.finish()
+}
+
+// Clap only declares conflicts with an argument, not with one of its values,
+// so the options that Morse output has no use for are rejected here instead,
+// with the same error that clap would give.
+fn check_morse_conflicts(cli: &Cli) -> Result<(), clap::Error> {
+    if !matches!(cli.format, Format::Morse) {
         return Ok(());
     }
+    let options = [
+        ("--as <ENCODING>", cli.encoding.is_some()),
+        ("--checksum", cli.checksum),
+        ("--annotate", cli.annotate),
+    ];
+    let Some((option, _)) = options.iter().find(|(_, is_present)| *is_present) else {
+        return Ok(());
+    };
+    let mut cmd = Cli::command();
+    let usage = cmd.render_usage();
+    let mut err = clap::Error::new(ErrorKind::ArgumentConflict).with_cmd(&cmd);
+    err.insert(
+        ContextKind::InvalidArg,
+        ContextValue::String((*option).to_string()),
+    );
+    err.insert(
+        ContextKind::PriorArg,
+        ContextValue::String("--format morse".to_string()),
+    );
+    err.insert(ContextKind::Usage, ContextValue::StyledStr(usage));
+    Err(err)
+}
+
+fn build_converter(cli: &Cli, alphabet: SpellingAlphabet) -> Result<PhoneticConverter> {
+    let mut converter = configure_converter(cli, alphabet);
 
-    for input in cli.input {
-        process_input(&input, &converter, cli.verbose);
+    if let Some(symbols) = cli.symbols.clone() {
+        converter = converter.symbol_vocabulary(&SymbolVocabulary::from(symbols));
+    }
+
+    if let Some(overrides_str) = &cli.overrides {
+        let overrides_map = parse_overrides(overrides_str).context("Failed to parse overrides")?;
+        converter = converter.with_overrides(overrides_map);
     }
 
+    Ok(converter)
+}
+
+// Create a converter with the output options, but without any of the symbol
+// vocabulary or overrides that change its code words.
+fn configure_converter(cli: &Cli, alphabet: SpellingAlphabet) -> PhoneticConverter {
+    let mut converter = PhoneticConverter::new(&alphabet)
+        .nonce_form(cli.nonce_form)
+        .mark_accents(cli.mark_accents)
+        .override_case(&OverrideCase::from(cli.override_case.clone()))
+        .word_style(&WordStyle::from(cli.word_style.clone()))
+        .unknown_chars(&UnknownChars::from(cli.unknown.clone()));
+
+    if let Some(glyphs) = cli.confusables.clone() {
+        converter = converter.confusable_glyphs(glyphs);
+    }
+
+    converter
+}
+
+// The converter for the chosen output format.
+enum Encoder {
+    Text(PhoneticConverter, TextMode, bool),
+    Morse(MorseEncoder),
+    Ssml(PhoneticConverter, SsmlOptions),
+    Audio {
+        morse: MorseEncoder,
+        synthesizer: Synthesizer,
+        sample_rate: u32,
+        path: PathBuf,
+        elements: Vec<MorseElement>,
+    },
+    Speech {
+        converter: PhoneticConverter,
+        pack: SoundPack,
+        char_gap: u32,
+        word_gap: u32,
+        path: PathBuf,
+        inputs: Vec<String>,
+    },
+}
+
+// How text output spells each input string.
+#[derive(Clone, Copy)]
+enum TextMode {
+    Plain,
+    Encoded(Encoding),
+    Annotated,
+}
+
+impl TextMode {
+    const fn new(encoding: Option<Encoding>, annotate: bool) -> Self {
+        match encoding {
+            Some(encoding) => Self::Encoded(encoding),
+            None if annotate => Self::Annotated,
+            None => Self::Plain,
+        }
+    }
+}
+
+impl Encoder {
+    // Writes any output that's gathered across all of the input strings.
+    fn finish(self) -> Result<()> {
+        let (path, sample_rate, samples) = match self {
+            Self::Text(..) | Self::Morse(_) | Self::Ssml(..) => return Ok(()),
+            Self::Audio {
+                synthesizer,
+                sample_rate,
+                path,
+                elements,
+                ..
+            } => (path, sample_rate, synthesizer.render(&elements)),
+            Self::Speech {
+                converter,
+                pack,
+                char_gap,
+                word_gap,
+                path,
+                inputs,
+            } => {
+                let tokens = converter.tokens(&inputs.join(" "));
+                let missing = pack.missing(&tokens);
+                if !missing.is_empty() {
+                    anyhow::bail!("Missing clips in sound pack: {}", missing.join(", "));
+                }
+                let samples = pack.assemble(&tokens, char_gap, word_gap);
+                (path, pack.sample_rate(), samples)
+            }
+        };
+
+        let file =
+            File::create(&path).with_context(|| format!("Failed to create {}", path.display()))?;
+        audio::write_wav(BufWriter::new(file), sample_rate, &samples)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+fn validate_input(input: &str, encoding: Option<Encoding>) -> Result<()> {
+    if let Some(encoding) = encoding {
+        encoding
+            .validate(input)
+            .with_context(|| format!("Invalid {} input: {input:?}", encoding_name(encoding)))?;
+    }
     Ok(())
 }
 
-fn process_input(input: &str, converter: &PhoneticConverter, verbose: bool) {
+const fn encoding_name(encoding: Encoding) -> &'static str {
+    match encoding {
+        Encoding::Base32 => "Base32",
+        Encoding::Base64 => "Base64",
+        Encoding::Hex => "hex",
+        Encoding::Uuid => "UUID",
+    }
+}
+
+fn process_input(input: &str, encoder: &mut Encoder, verbose: bool) {
+    match encoder {
+        Encoder::Text(converter, mode, checksum) => {
+            let mut output = match mode {
+                TextMode::Plain => converter.convert(input),
+                TextMode::Encoded(encoding) => converter.convert_encoded(input, *encoding),
+                TextMode::Annotated => converter.convert_annotated(input),
+            };
+            if *checksum {
+                output = format!("{output} / {}", converter.convert_checksum(input));
+            }
+            print_output(input, &output, verbose);
+            if matches!(mode, TextMode::Annotated) {
+                print_glyph_summary(&converter.ambiguous_glyphs(input));
+            }
+        }
+        Encoder::Morse(morse) => print_output(input, &morse.encode(input), verbose),
+        Encoder::Ssml(converter, options) => {
+            print_output(input, &converter.to_ssml(input, options), verbose);
+        }
+        Encoder::Audio {
+            morse, elements, ..
+        } => {
+            let input_elements = morse.elements(input);
+            if !elements.is_empty() && !input_elements.is_empty() {
+                elements.push(MorseElement::WordGap);
+            }
+            elements.extend(input_elements);
+        }
+        Encoder::Speech { inputs, .. } => inputs.push(input.to_string()),
+    }
+}
+
+fn print_output(input: &str, output: &str, verbose: bool) {
     if verbose {
         print!("{input} -> ");
     }
-    println!("{}", converter.convert(input));
+    println!("{output}");
+}
+
+fn print_glyph_summary(glyphs: &[String]) {
+    if glyphs.is_empty() {
+        println!("Ambiguous glyphs: 0");
+    } else {
+        println!("Ambiguous glyphs: {} ({})", glyphs.len(), glyphs.join(", "));
+    }
+}
+
+fn list_alphabets(era: &EraFilter) {
+    for value in Alphabet::value_variants() {
+        let alphabet = SpellingAlphabet::from(value.clone());
+        if !era.matches(alphabet.era()) {
+            continue;
+        }
+        let name = value.to_possible_value().expect("no skipped variants");
+        let year = alphabet
+            .year()
+            .map(|year| year.to_string())
+            .unwrap_or_default();
+        println!("{:<16} {year:<4}  {}", name.get_name(), alphabet.source());
+    }
+}
+
+// Run the analysis chosen by `--check`, `--diff`, or `--verify`.
+fn analyze(cli: &Cli, converter: &PhoneticConverter) -> Result<()> {
+    if cli.check {
+        return check(converter, cli.threshold, cli.top);
+    }
+
+    if let Some(first) = &cli.diff {
+        // only the `--alphabet` takes the symbols and overrides
+        let first = configure_converter(cli, SpellingAlphabet::from(first.clone()));
+        let changes = first.diff(converter);
+        match cli.diff_format {
+            DiffFormat::Json => println!("{}", diff::to_json(&changes)),
+            DiffFormat::Table => diff::print_table(&changes),
+        }
+        return Ok(());
+    }
+
+    if let Some(checksum) = &cli.verify {
+        let [input] = cli.input.as_slice() else {
+            anyhow::bail!("Expected a single input string to verify");
+        };
+        converter
+            .verify(input, checksum)
+            .context("Failed to verify the checksum")?;
+        println!("Checksum matches");
+    }
+    Ok(())
+}
+
+fn check(converter: &PhoneticConverter, threshold: f64, top: usize) -> Result<()> {
+    let pairs = converter.confusable_pairs(0.0);
+    let width = pairs
+        .iter()
+        .take(top)
+        .map(|pair| pair.code_words().0.as_str().chars().count())
+        .max()
+        .unwrap_or_default();
+    for pair in pairs.iter().take(top) {
+        let (a, b) = pair.characters();
+        let (a_word, b_word) = pair.code_words();
+        let marker = if pair.is_overridden() {
+            "  (override)"
+        } else {
+            ""
+        };
+        println!(
+            "{:.2}  {a} -> {:<width$}  {b} -> {b_word}{marker}",
+            pair.similarity(),
+            a_word.as_str()
+        );
+    }
+
+    let failures: Vec<_> = pairs
+        .iter()
+        .filter(|pair| pair.is_overridden() && pair.similarity() >= threshold)
+        .map(|pair| {
+            let (a, b) = pair.characters();
+            format!("{a}/{b} ({:.2})", pair.similarity())
+        })
+        .collect();
+    if !failures.is_empty() {
+        anyhow::bail!(
+            "Overrides sound too alike at a threshold of {threshold}: {}",
+            failures.join(", ")
+        );
+    }
+    Ok(())
 }
 
 fn parse_overrides(input: &str) -> Result<HashMap<char, String>> {
@@ -132,3 +469,74 @@ fn generate_man_page() -> Result<()> {
 fn verify_cli() {
     Cli::command().debug_assert();
 }
+
+#[test]
+fn verify_morse_conflicts() {
+    for option in ["--as=hex", "--checksum", "--annotate"] {
+        let cli = Cli::parse_from(["spellout", "--format", "morse", option, "abc"]);
+        let err = check_morse_conflicts(&cli).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+
+        let cli = Cli::parse_from(["spellout", "--format", "respelling", option, "abc"]);
+        assert!(check_morse_conflicts(&cli).is_ok());
+    }
+    let cli = Cli::parse_from(["spellout", "--format", "morse", "abc"]);
+    assert!(check_morse_conflicts(&cli).is_ok());
+}
+
+#[test]
+fn verify_wav_conflicts() {
+    // audio output has no use for an output format
+    let args = ["spellout", "--wav", "out.wav", "--format", "text", "a"];
+    let err = Cli::try_parse_from(args).err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::ArgumentConflict);
+    assert!(Cli::try_parse_from(["spellout", "--wav", "out.wav", "a"]).is_ok());
+}
+
+#[test]
+fn verify_diff_options() {
+    let cli = Cli::parse_from(["spellout", "--mark-accents", "--overrides", "a=Apple"]);
+    let first = configure_converter(&cli, SpellingAlphabet::Greek);
+    let second = build_converter(&cli, SpellingAlphabet::Greek).unwrap();
+    let changes = first.diff(&second);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].character(), 'a');
+}
+
+#[test]
+fn verify_check() {
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato);
+    assert!(check(&converter, 0.85, 0).is_ok());
+
+    // built-in pairs are only reported, since there's nothing to fix
+    let converter = PhoneticConverter::new(&SpellingAlphabet::UsTelephone);
+    assert!(
+        converter
+            .confusable_pairs(0.85)
+            .iter()
+            .any(|pair| !pair.is_overridden())
+    );
+    assert!(check(&converter, 0.85, 0).is_ok());
+
+    let overrides = parse_overrides("b=Bee,d=Dee").unwrap();
+    let converter = PhoneticConverter::new(&SpellingAlphabet::Nato).with_overrides(overrides);
+    assert!(check(&converter, 0.85, 0).is_err());
+    assert!(check(&converter, 0.9, 0).is_ok());
+}
+
+#[test]
+fn verify_word_input() {
+    // words that name an analysis or help are spelled like any other input
+    for word in ["check", "diff", "verify", "help"] {
+        let cli = Cli::try_parse_from(["spellout", word]).unwrap();
+        assert_eq!(cli.input, [word]);
+        assert!(!cli.check && cli.diff.is_none() && cli.verify.is_none());
+    }
+
+    let cli = Cli::try_parse_from(["spellout", "check"]).unwrap();
+    let converter = build_converter(&cli, SpellingAlphabet::from(cli.alphabet.clone())).unwrap();
+    assert_eq!(
+        converter.convert(&cli.input[0]),
+        "charlie hotel echo charlie kilo"
+    );
+}
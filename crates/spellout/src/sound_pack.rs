@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use spellabet::Token;
+
+use crate::audio;
+
+// The name of the file describing the clips of a sound pack.
+const MANIFEST: &str = "manifest.txt";
+
+/// A set of recorded clips for spoken code words.
+///
+/// A sound pack is a directory of mono, 16-bit PCM WAV clips along with a
+/// manifest file. Each line of the manifest names a code word, optionally
+/// followed by `=` and the file name of its clip, which otherwise defaults to
+/// the code word with a `.wav` extension. Blank lines and lines starting with
+/// `#` are ignored.
+pub struct SoundPack {
+    /// The sample rate shared by every clip.
+    sample_rate: u32,
+    /// The samples of each clip, by lowercase code word.
+    clips: HashMap<String, Vec<i16>>,
+}
+
+impl SoundPack {
+    /// Loads the sound pack from the given directory, reporting every clip
+    /// that's missing or unreadable at once.
+    pub fn load(dir: &Path) -> Result<Self> {
+        let manifest_path = dir.join(MANIFEST);
+        let manifest = fs::read_to_string(&manifest_path)
+            .with_context(|| format!("Failed to read {}", manifest_path.display()))?;
+
+        let mut sample_rate = None;
+        let mut clips = HashMap::new();
+        let mut problems = Vec::new();
+        for line in manifest.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (word, file) = match line.split_once('=') {
+                Some((word, file)) => (word.trim(), file.trim().to_string()),
+                None => (line, format!("{line}.wav")),
+            };
+
+            let clip = fs::read(dir.join(&file))
+                .map_err(|err| err.to_string())
+                .and_then(|bytes| audio::read_wav(&bytes));
+            match clip {
+                Ok((rate, _)) if sample_rate.is_some_and(|sample_rate| sample_rate != rate) => {
+                    problems.push(format!("{file}: sample rate differs from other clips"));
+                }
+                Ok((rate, samples)) => {
+                    sample_rate = Some(rate);
+                    clips.insert(word.to_lowercase(), samples);
+                }
+                Err(err) => problems.push(format!("{file}: {err}")),
+            }
+        }
+
+        if !problems.is_empty() {
+            anyhow::bail!("Invalid clips in sound pack:\n  {}", problems.join("\n  "));
+        }
+        let sample_rate = sample_rate
+            .with_context(|| format!("No clips listed in {}", manifest_path.display()))?;
+
+        Ok(Self { sample_rate, clips })
+    }
+
+    pub const fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Returns the code words of the tokens that have no clip, along with any
+    /// characters that couldn't be converted, in order of first appearance.
+    pub fn missing(&self, tokens: &[Token]) -> Vec<String> {
+        let mut missing: Vec<String> = Vec::new();
+        for token in tokens {
+            if token.character().is_whitespace() {
+                continue;
+            }
+            let unconverted = (!token.is_converted()).then(|| format!("'{}'", token.character()));
+            let words = token.code_words().iter().map(|word| word.to_lowercase());
+            for word in unconverted.into_iter().chain(words) {
+                if !self.clips.contains_key(&word) && !missing.contains(&word) {
+                    missing.push(word);
+                }
+            }
+        }
+        missing
+    }
+
+    /// Concatenates the clips of the tokens, separated by the character gap,
+    /// with whitespace characters becoming the word gap. Gaps are given in
+    /// milliseconds.
+    pub fn assemble(&self, tokens: &[Token], char_gap: u32, word_gap: u32) -> Vec<i16> {
+        let gap_len = |millis: u32| {
+            let len = u64::from(self.sample_rate) * u64::from(millis) / 1000;
+            usize::try_from(len).expect("gap fits in memory")
+        };
+        let (char_gap, word_gap) = (gap_len(char_gap), gap_len(word_gap));
+
+        let mut samples = Vec::new();
+        let mut gap = None;
+        for token in tokens {
+            if token.character().is_whitespace() {
+                gap = gap.map(|_| word_gap);
+                continue;
+            }
+            for word in token.code_words() {
+                let Some(clip) = self.clips.get(&word.to_lowercase()) else {
+                    continue;
+                };
+                if let Some(gap) = gap {
+                    samples.resize(samples.len() + gap, 0);
+                }
+                samples.extend_from_slice(clip);
+                gap = Some(char_gap);
+            }
+        }
+        samples
+    }
+}